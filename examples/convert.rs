@@ -0,0 +1,25 @@
+// shows an HT date in every layout this crate writes
+//
+// cargo run --example convert -- 2019GA01T31S2000R
+use ht_timeparser::{HTDate, HTFormat};
+
+fn main() {
+    let input = std::env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: convert DATE");
+        std::process::exit(2);
+    });
+    let date = HTDate::interpret_string(&input)
+        .or_else(|_| HTDate::interpret_week_string(&input))
+        .or_else(|_| HTDate::interpret_ordinal_string(&input))
+        .expect("not a date this crate can read");
+    if let Some(format) = HTDate::detect_format(&input) {
+        println!("read as {:?}", format);
+    }
+    for format in HTFormat::ALL {
+        println!("{:<16} {}", format!("{:?}", format), date.to_string_as(format));
+    }
+    println!("{:<16} {}", "Prose", date.to_prose_string());
+    if let Ok(id) = date.to_base36() {
+        println!("{:<16} {}", "Base36", id);
+    }
+}
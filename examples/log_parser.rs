@@ -0,0 +1,33 @@
+// reads log lines from stdin that start with an HT date, e.g.
+//   2019-GA-01T31S2000R backup finished
+// and prints how many parsed, how many needed lenient parsing and the span they cover
+//
+// cargo run --example log_parser < app.log
+use std::io::BufRead;
+
+use ht_timeparser::duration::HTDuration;
+use ht_timeparser::{HTParseOptions, HTParseSession};
+
+fn main() {
+    let mut session = HTParseSession::new(HTParseOptions::LENIENT);
+    let mut first = None;
+    let mut last = None;
+    let mut unparsed = 0;
+    for line in std::io::stdin().lock().lines() {
+        let line = line.expect("couldn't read stdin");
+        let timestamp = line.split(' ').next().unwrap_or("");
+        match session.interpret_string(timestamp) {
+            Ok(date) => {
+                first.get_or_insert(date);
+                last = Some(date);
+            }
+            Err(_) => unparsed += 1,
+        }
+    }
+    println!("parsed {} lines, {} without a timestamp", session.parsed(), unparsed);
+    println!("lenient parses: {}", session.counts());
+    if let (Some(first), Some(last)) = (first, last) {
+        let span = last.checked_duration_since(&first).unwrap_or(HTDuration::ZERO);
+        println!("from {} to {}, {:#}", first, last, span);
+    }
+}
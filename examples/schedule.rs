@@ -0,0 +1,25 @@
+// prints n evenly spaced dates between two HT dates, aligned to the day if asked
+//
+// cargo run --example schedule -- 2019-GA-01 2019-GS-01 5 [--daily]
+use ht_timeparser::interval::HTInterval;
+use ht_timeparser::{Granularity, HTDate};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 3 {
+        eprintln!("usage: schedule START END COUNT [--daily]");
+        std::process::exit(2);
+    }
+    let start = HTDate::interpret_string(&args[0]).expect("invalid start date");
+    let end = HTDate::interpret_string(&args[1]).expect("invalid end date");
+    let count: usize = args[2].parse().expect("invalid count");
+    let interval = HTInterval::new(start, end).expect("end is before start");
+    let dates = if args.get(3).map(String::as_str) == Some("--daily") {
+        interval.evenly_spaced_aligned(count, Granularity::Day)
+    } else {
+        interval.evenly_spaced(count)
+    };
+    for date in dates {
+        println!("{}  {:#}", date, date);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::period::HTPeriod;
+use crate::{HTDate, DAYS_PER_MONTH, DAYS_PER_YEAR, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+// the time from one date to another in calendar units. every month is 24 days and every year
+// is 10 months, so whole years and months can be read straight off the tick count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HTBreakdown {
+    // set when the second date is before the first, all other fields are magnitudes
+    pub negative: bool,
+    pub years: u128,
+    pub months: u8,
+    pub days: u8,
+    pub sks: u128,
+    pub rem: u128,
+    pub total_ticks: u128,
+}
+
+// None if either date is too far out to count in ticks
+pub fn calendar_breakdown(a: &HTDate, b: &HTDate) -> Option<HTBreakdown> {
+    let from = a.checked_epoch()?;
+    let to = b.checked_epoch()?;
+    let total_ticks = from.abs_diff(to);
+    let days = total_ticks / SECONDS_PER_DAY;
+    let ticks_of_day = total_ticks % SECONDS_PER_DAY;
+    let day_of_year = days % DAYS_PER_YEAR as u128;
+    Some(HTBreakdown {
+        negative: to < from,
+        years: days / DAYS_PER_YEAR as u128,
+        months: (day_of_year / DAYS_PER_MONTH as u128) as u8,
+        days: (day_of_year % DAYS_PER_MONTH as u128) as u8,
+        sks: ticks_of_day / SECONDS_PER_SKS,
+        rem: ticks_of_day % SECONDS_PER_SKS,
+        total_ticks,
+    })
+}
+
+// how old something from one date is at a later one, counting whole years and months on the
+// calendar like birthdays and anniversaries, then the days, sks and remainder left over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HTAge {
+    pub years: u64,
+    pub months: u8,
+    pub days: u8,
+    pub sks: u128,
+    pub rem: u128,
+}
+
+impl HTDate {
+    // the age at self of something from other. None if other is later, or too far back for the
+    // years to fit
+    pub fn since(&self, other: &HTDate) -> Option<HTAge> {
+        if self.checked_epoch()? < other.checked_epoch()? {
+            return None;
+        }
+        // a time of day earlier than other's borrows a day
+        let (end, ticks) = if self.second < other.second {
+            (self.pred_day()?, self.second + SECONDS_PER_DAY - other.second)
+        } else {
+            (*self, self.second - other.second)
+        };
+        let period = HTPeriod::between(other, &end)?;
+        Some(HTAge {
+            years: u64::try_from(period.years).ok()?,
+            months: u8::try_from(period.months).ok()?,
+            days: u8::try_from(period.days).ok()?,
+            sks: ticks / SECONDS_PER_SKS,
+            rem: ticks % SECONDS_PER_SKS,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn breaks_down_into_calendar_units() {
+        let a = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let b = HTDate::new(2021, MonthStatus::Greater, Month::Funny, 4, 40 * 6000 + 1000);
+        let breakdown = calendar_breakdown(&a, &b).unwrap();
+        assert_eq!(breakdown, HTBreakdown {
+            negative: false,
+            years: 2,
+            months: 2,
+            days: 3,
+            sks: 8,
+            rem: 5000,
+            total_ticks: b.checked_epoch().unwrap() - a.checked_epoch().unwrap(),
+        });
+        let reversed = calendar_breakdown(&b, &a).unwrap();
+        assert!(reversed.negative);
+        assert_eq!(reversed.total_ticks, breakdown.total_ticks);
+        assert_eq!(calendar_breakdown(&a, &a), Some(HTBreakdown::default()));
+    }
+
+    #[test]
+    fn counts_ages_on_the_calendar() {
+        let born = HTDate::new(2001, MonthStatus::Lesser, Month::Smosh, 20, 10 * 6000);
+        let now = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 3, 5 * 6000 + 12);
+        assert_eq!(now.since(&born), Some(HTAge {
+            years: 17,
+            months: 3,
+            days: 6,
+            sks: 95,
+            rem: 12,
+        }));
+        let birthday = HTDate::new(2019, MonthStatus::Lesser, Month::Smosh, 20, 10 * 6000);
+        assert_eq!(birthday.since(&born), Some(HTAge { years: 18, ..HTAge::default() }));
+        assert_eq!(born.since(&now), None);
+        assert_eq!(born.since(&born), Some(HTAge::default()));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{days_in_month, HTDate, Month, MonthStatus, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HTBuildError {
+    // 0 or past the end of the month
+    DayOutOfRange,
+    // sks and remainder together reach the next day
+    SecondOutOfRange,
+    RemainderOutOfRange,
+}
+
+// fields left unset default to HTDate::EPOCH's: year 0, Greater Zero, day 1, the start of the day
+//
+// let date = HTDate::builder().year(2019).month(MonthStatus::Greater, Month::Apress).sks(31).build()?;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTDateBuilder {
+    year: u128,
+    month: (MonthStatus, Month),
+    day: u8,
+    sks: u128,
+    rem: u128,
+}
+
+impl Default for HTDateBuilder {
+    fn default() -> Self {
+        HTDateBuilder {
+            year: HTDate::EPOCH.year,
+            month: HTDate::EPOCH.month,
+            day: HTDate::EPOCH.day,
+            sks: 0,
+            rem: 0,
+        }
+    }
+}
+
+impl HTDateBuilder {
+    pub fn new() -> Self {
+        HTDateBuilder::default()
+    }
+
+    pub fn year(mut self, year: u128) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn month(mut self, month_status: MonthStatus, month: Month) -> Self {
+        self.month = (month_status, month);
+        self
+    }
+
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+
+    pub fn sks(mut self, sks: u128) -> Self {
+        self.sks = sks;
+        self
+    }
+
+    pub fn remainder(mut self, rem: u128) -> Self {
+        self.rem = rem;
+        self
+    }
+
+    // the whole time of day, replacing any sks and remainder set before
+    pub fn second(mut self, second: u128) -> Self {
+        self.sks = second / SECONDS_PER_SKS;
+        self.rem = second % SECONDS_PER_SKS;
+        self
+    }
+
+    pub fn build(&self) -> Result<HTDate, HTBuildError> {
+        if self.day == 0 || self.day > days_in_month(self.year, self.month.0, self.month.1) {
+            return Err(HTBuildError::DayOutOfRange);
+        }
+        if self.rem >= SECONDS_PER_SKS {
+            return Err(HTBuildError::RemainderOutOfRange);
+        }
+        let second = self.sks.checked_mul(SECONDS_PER_SKS).map(|ticks| ticks + self.rem).filter(|second| *second < SECONDS_PER_DAY);
+        let second = second.ok_or(HTBuildError::SecondOutOfRange)?;
+        Ok(HTDate::new(self.year, self.month.0, self.month.1, self.day, second))
+    }
+}
+
+impl HTDate {
+    pub fn builder() -> HTDateBuilder {
+        HTDateBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_with_defaults() {
+        assert_eq!(HTDate::builder().build(), Ok(HTDate::EPOCH));
+        let date = HTDate::builder().year(2019).month(MonthStatus::Greater, Month::Apress).sks(31).remainder(2000).build();
+        assert_eq!(date, Ok(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000)));
+        let replaced = HTDate::builder().sks(5).remainder(5).second(12).build();
+        assert_eq!(replaced.map(|date| date.second), Ok(12));
+    }
+
+    #[test]
+    fn rejects_inconsistent_fields() {
+        assert_eq!(HTDate::builder().day(0).build(), Err(HTBuildError::DayOutOfRange));
+        assert_eq!(HTDate::builder().day(25).build(), Err(HTBuildError::DayOutOfRange));
+        assert_eq!(HTDate::builder().remainder(6000).build(), Err(HTBuildError::RemainderOutOfRange));
+        assert_eq!(HTDate::builder().sks(100).build(), Err(HTBuildError::SecondOutOfRange));
+        assert_eq!(HTDate::builder().sks(u128::MAX).build(), Err(HTBuildError::SecondOutOfRange));
+    }
+}
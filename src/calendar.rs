@@ -0,0 +1,143 @@
+use crate::{HTDate, Month, MonthStatus, MONTH_ORDER};
+
+// the month sequence and month lengths dates are checked and counted against. StandardCalendar
+// is the one the crate uses by default; other calendars can reorder or drop months and give them
+// other lengths, and get validation and day arithmetic from the provided methods. the parser,
+// FormatDescription and the ordinal strings take a calendar through their _in variants
+pub trait HTCalendar {
+    // months in the order they happen within a year
+    fn months(&self) -> &[(MonthStatus, Month)];
+
+    fn days_in_month(&self, year: u128, month_status: MonthStatus, month: Month) -> u8;
+
+    fn days_in_year(&self, year: u128) -> u32 {
+        self.months().iter().map(|(status, month)| self.days_in_month(year, *status, *month) as u32).sum()
+    }
+
+    // zero-based position of the month within the year, None if the calendar doesn't have it
+    fn month_position(&self, month: (MonthStatus, Month)) -> Option<usize> {
+        self.months().iter().position(|candidate| *candidate == month)
+    }
+
+    fn is_valid(&self, date: &HTDate) -> bool {
+        date.validated_in(self).is_ok()
+    }
+
+    // 1-based day of the year
+    fn ordinal(&self, date: &HTDate) -> Option<u32> {
+        let position = self.month_position(date.month)?;
+        let before: u32 = self.months()[..position].iter().map(|(status, month)| self.days_in_month(date.year, *status, *month) as u32).sum();
+        Some(before + date.day as u32)
+    }
+
+    fn date_at_ordinal(&self, year: u128, ordinal: u32) -> Option<HTDate> {
+        let mut left = ordinal.checked_sub(1)?;
+        for (status, month) in self.months() {
+            let length = self.days_in_month(year, *status, *month) as u32;
+            if left < length {
+                return Some(HTDate::new(year, *status, *month, left as u8 + 1, 0));
+            }
+            left -= length;
+        }
+        None
+    }
+
+    // the next day at the same time, rolling over this calendar's month and year ends
+    fn next_day(&self, date: &HTDate) -> Option<HTDate> {
+        let ordinal = self.ordinal(date)?;
+        let next = if ordinal < self.days_in_year(date.year) {
+            self.date_at_ordinal(date.year, ordinal + 1)?
+        } else {
+            self.date_at_ordinal(date.year.checked_add(1)?, 1)?
+        };
+        Some(HTDate { second: date.second, ..next })
+    }
+}
+
+// MONTH_ORDER with days_in_month, the calendar the parser and formatters assume
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StandardCalendar;
+
+impl HTCalendar for StandardCalendar {
+    fn months(&self) -> &[(MonthStatus, Month)] {
+        &MONTH_ORDER
+    }
+
+    fn days_in_month(&self, year: u128, month_status: MonthStatus, month: Month) -> u8 {
+        crate::days_in_month(year, month_status, month)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HTParseError;
+
+    // a homebrew variant with only the greater months, 30 days each
+    struct GreaterOnly;
+
+    impl HTCalendar for GreaterOnly {
+        fn months(&self) -> &[(MonthStatus, Month)] {
+            &MONTH_ORDER[..5]
+        }
+
+        fn days_in_month(&self, _year: u128, _status: MonthStatus, _month: Month) -> u8 {
+            30
+        }
+    }
+
+    #[test]
+    fn standard_calendar_matches_the_crate() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 24, 100);
+        assert_eq!(StandardCalendar.ordinal(&date), Some(date.ordinal() as u32));
+        assert_eq!(StandardCalendar.days_in_year(2019), crate::DAYS_PER_YEAR as u32);
+        assert_eq!(StandardCalendar.next_day(&date), date.succ_day());
+        assert_eq!(StandardCalendar.date_at_ordinal(2019, 240), Some(HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0)));
+        assert!(StandardCalendar.is_valid(&date));
+    }
+
+    #[test]
+    fn other_calendars_bring_their_own_tables() {
+        let long_day = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 30, 0);
+        assert!(GreaterOnly.is_valid(&long_day));
+        assert!(!StandardCalendar.is_valid(&long_day));
+        assert!(!GreaterOnly.is_valid(&HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0)));
+        assert_eq!(GreaterOnly.next_day(&long_day), Some(HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 0)));
+        let last = HTDate::new(2019, MonthStatus::Greater, Month::Funny, 30, 5);
+        assert_eq!(GreaterOnly.next_day(&last), Some(HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 5)));
+        assert_eq!(GreaterOnly.date_at_ordinal(2019, 151), None);
+    }
+
+    #[test]
+    fn standard_validation_agrees_with_the_const_one() {
+        for (status, month) in MONTH_ORDER {
+            for day in 0..=25 {
+                let date = HTDate::new(2019, status, month, day, 0);
+                assert_eq!(date.validated_in(&StandardCalendar), HTDate::new_checked(2019, status, month, day, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn parses_and_counts_in_other_calendars() {
+        let long_day = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 30, 31 * 6000 + 2000);
+        assert_eq!(HTDate::interpret_string_in("2019-GA-30T31S2000R", &GreaterOnly), Ok(long_day));
+        assert_eq!(HTDate::interpret_string("2019-GA-30T31S2000R"), Err(HTParseError::TooManyDays));
+        assert_eq!(HTDate::interpret_string_in("2019GA31", &GreaterOnly), Err(HTParseError::TooManyDays));
+        assert_eq!(HTDate::interpret_string_in("2019-LA-01", &GreaterOnly), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::interpret_ordinal_string_in("2019-090", &GreaterOnly), Ok(long_day.start_of_day()));
+        assert_eq!(HTDate::interpret_ordinal_string_in("2019-151", &GreaterOnly), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::interpret_ordinal_string_in("2019-240", &StandardCalendar), HTDate::interpret_ordinal_string("2019-240"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formats_and_reads_back_in_other_calendars() {
+        let long_day = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 30, 0);
+        assert_eq!(long_day.to_ordinal_string_in(&GreaterOnly).as_deref(), Some("2019-090"));
+        assert_eq!(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0).to_ordinal_string_in(&GreaterOnly), None);
+        let desc = crate::format::FormatDescription::parse("%-D %G%M %Y").unwrap();
+        assert_eq!(desc.interpret_in("30 GA 2019", &GreaterOnly), Ok(long_day));
+        assert_eq!(desc.interpret("30 GA 2019"), Err(HTParseError::TooManyDays));
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, NaiveDateTime};
+
+use crate::gregorian::HTEpochMapping;
+use crate::HTDate;
+
+// conversions to and from chrono's UTC wall clock time, through HTEpochMapping::DEFAULT unless a
+// mapping is given. None before the HT epoch or past the range chrono can hold
+impl HTDate {
+    pub fn to_naive_datetime(&self) -> Option<NaiveDateTime> {
+        self.to_naive_datetime_with(&HTEpochMapping::DEFAULT)
+    }
+
+    pub fn to_naive_datetime_with(&self, mapping: &HTEpochMapping) -> Option<NaiveDateTime> {
+        let (seconds, nanos) = mapping.to_unix_parts(self)?;
+        DateTime::from_timestamp(seconds, nanos).map(|datetime| datetime.naive_utc())
+    }
+
+    // rounded down to the tick
+    pub fn from_naive_datetime(datetime: &NaiveDateTime) -> Option<Self> {
+        HTDate::from_naive_datetime_with(datetime, &HTEpochMapping::DEFAULT)
+    }
+
+    pub fn from_naive_datetime_with(datetime: &NaiveDateTime, mapping: &HTEpochMapping) -> Option<Self> {
+        let utc = datetime.and_utc();
+        mapping.from_unix_parts(utc.timestamp(), utc.timestamp_subsec_nanos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+    use chrono::NaiveDate;
+
+    fn naive(year: i32, month: u32, day: u32, hour: u32, minute: u32, milli: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_milli_opt(hour, minute, 0, milli).unwrap()
+    }
+
+    #[test]
+    fn converts_at_the_default_mapping() {
+        assert_eq!(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0).to_naive_datetime(), Some(naive(1970, 1, 1, 0, 0, 0)));
+        // the 25th earth day is the first day of the second month, and noon is half a day of ticks
+        assert_eq!(HTDate::from_naive_datetime(&naive(1970, 1, 25, 12, 0, 0)), Some(HTDate::new(0, MonthStatus::Greater, Month::Niktvirin, 1, 300_000)));
+        assert_eq!(HTDate::from_naive_datetime(&naive(1970, 1, 1, 0, 0, 143)), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0)));
+        assert_eq!(HTDate::from_naive_datetime(&naive(1969, 12, 31, 23, 59, 0)), None);
+        let date = HTDate::new(56, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        assert_eq!(HTDate::from_naive_datetime(&date.to_naive_datetime().unwrap()), Some(date));
+    }
+
+    #[test]
+    fn converts_with_another_mapping() {
+        let mapping = HTEpochMapping::from_unix_seconds(86_400, 144_000_000);
+        assert_eq!(HTDate::from_naive_datetime_with(&naive(1970, 1, 2, 0, 0, 0), &mapping), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0)));
+        // far enough out that chrono can't hold it
+        assert_eq!(HTDate::new(1_000_000_000, MonthStatus::Greater, Month::Zero, 1, 0).to_naive_datetime_with(&mapping), None);
+    }
+}
@@ -0,0 +1,109 @@
+use core::cell::Cell;
+
+use crate::duration::HTDuration;
+#[cfg(feature = "std")]
+use crate::gregorian::HTEpochMapping;
+use crate::HTDate;
+
+// where the current HT time comes from, so code that stamps things can be handed a MockClock in
+// tests instead of reading the OS clock
+pub trait HTClock {
+    fn now(&self) -> HTDate;
+}
+
+// the OS clock through an epoch mapping. panics if the clock reads earlier than the mapping's HT
+// epoch
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock {
+    mapping: HTEpochMapping,
+}
+
+#[cfg(feature = "std")]
+impl SystemClock {
+    pub fn new(mapping: HTEpochMapping) -> Self {
+        SystemClock { mapping }
+    }
+
+    pub fn mapping(&self) -> &HTEpochMapping {
+        &self.mapping
+    }
+}
+
+#[cfg(feature = "std")]
+impl HTClock for SystemClock {
+    fn now(&self) -> HTDate {
+        HTDate::from_system_time_with(std::time::SystemTime::now(), &self.mapping).expect("system clock is before the HT epoch")
+    }
+}
+
+// a clock that only moves when told to
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Cell<HTDate>,
+}
+
+impl MockClock {
+    pub fn new(start: HTDate) -> Self {
+        MockClock { now: Cell::new(start) }
+    }
+
+    pub fn set(&self, date: HTDate) {
+        self.now.set(date);
+    }
+
+    // moves by the duration, backwards for a negative one. panics on going past the first or last
+    // representable date
+    pub fn advance(&self, by: HTDuration) {
+        let next = self.now.get().checked_add_duration(by).expect("MockClock moved out of range");
+        self.now.set(next);
+    }
+}
+
+impl HTClock for MockClock {
+    fn now(&self) -> HTDate {
+        self.now.get()
+    }
+}
+
+impl HTDate {
+    // the current time from the OS clock, through HTEpochMapping::DEFAULT
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        SystemClock::default().now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    fn stamp(clock: &dyn HTClock) -> HTDate {
+        clock.now()
+    }
+
+    #[test]
+    fn freezes_and_steps_a_mock_clock() {
+        let start = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, 599_000);
+        let clock = MockClock::new(start);
+        assert_eq!(stamp(&clock), start);
+        assert_eq!(stamp(&clock), start);
+        clock.advance(HTDuration::from_ticks(1000));
+        assert_eq!(stamp(&clock), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 0));
+        clock.advance(HTDuration::from_ticks(-1));
+        assert_eq!(stamp(&clock), HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, 599_999));
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reads_the_system_clock() {
+        let before = HTDate::now();
+        let after = SystemClock::new(HTEpochMapping::DEFAULT).now();
+        assert!(before.checked_epoch() <= after.checked_epoch());
+        // well past 1970 at the default mapping
+        assert!(before.year > 50);
+    }
+}
@@ -0,0 +1,304 @@
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use crate::{HTParseError, HTParseOptions, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+// signed length of time counted in ticks, the same unit as HTDate::second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HTDuration {
+    ticks: i128,
+}
+
+impl HTDuration {
+    pub const ZERO: HTDuration = HTDuration { ticks: 0 };
+
+    pub fn from_ticks(ticks: i128) -> Self {
+        HTDuration { ticks }
+    }
+
+    // i64 so these can't overflow
+    pub fn from_days(days: i64) -> Self {
+        HTDuration::from_ticks(days as i128 * SECONDS_PER_DAY as i128)
+    }
+
+    pub fn from_sks(sks: i64) -> Self {
+        HTDuration::from_ticks(sks as i128 * SECONDS_PER_SKS as i128)
+    }
+
+    pub fn ticks(&self) -> i128 {
+        self.ticks
+    }
+
+    // rounded towards zero, like the parts Display shows
+    pub fn whole_days(&self) -> i128 {
+        self.ticks / SECONDS_PER_DAY as i128
+    }
+
+    pub fn whole_sks(&self) -> i128 {
+        self.ticks / SECONDS_PER_SKS as i128
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.ticks < 0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.ticks == 0
+    }
+
+    // None for the most negative duration, whose magnitude doesn't fit
+    pub fn checked_abs(&self) -> Option<Self> {
+        self.ticks.checked_abs().map(HTDuration::from_ticks)
+    }
+
+    pub fn checked_add(&self, other: HTDuration) -> Option<Self> {
+        self.ticks.checked_add(other.ticks).map(HTDuration::from_ticks)
+    }
+
+    pub fn checked_sub(&self, other: HTDuration) -> Option<Self> {
+        self.ticks.checked_sub(other.ticks).map(HTDuration::from_ticks)
+    }
+
+    pub fn checked_mul(&self, factor: i128) -> Option<Self> {
+        self.ticks.checked_mul(factor).map(HTDuration::from_ticks)
+    }
+
+    // rounded towards zero, None when dividing by zero
+    pub fn checked_div(&self, divisor: i128) -> Option<Self> {
+        self.ticks.checked_div(divisor).map(HTDuration::from_ticks)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        self.ticks.checked_neg().map(HTDuration::from_ticks)
+    }
+
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        HTDuration::interpret_string_with(input, &HTParseOptions::default())
+    }
+
+    // P3DT12S0500R, any unit can be left out but D comes before the T and S before R.
+    // allow_any_unit_order also takes "T5S 3D" or "500R 3D", still with each unit at most once
+    pub fn interpret_string_with(input: &str, options: &HTParseOptions) -> Result<Self, HTParseError> {
+        // letters are compared through this rather than uppercasing a copy, so no allocation
+        let upper = |c: char| if options.allow_lowercase { c.to_ascii_uppercase() } else { c };
+        let mut rest = input;
+        let negative = rest.starts_with('-');
+        rest = rest.strip_prefix('-').unwrap_or(rest);
+        if options.allow_any_unit_order {
+            rest = rest.strip_prefix(|c| upper(c) == 'P').unwrap_or(rest);
+        } else {
+            rest = rest.strip_prefix(|c| upper(c) == 'P').ok_or(HTParseError::MalformedString)?;
+        }
+        // days, sks, rem in the order strict mode wants them
+        let mut amounts: [Option<u128>; 3] = [None; 3];
+        let mut in_time = false;
+        let mut last = None;
+        while !rest.is_empty() {
+            if options.allow_any_unit_order {
+                let trimmed = rest.trim_start_matches(|c| c == ' ' || upper(c) == 'T');
+                if trimmed.len() != rest.len() {
+                    rest = trimmed;
+                    continue;
+                }
+            } else if let Some(after) = rest.strip_prefix(|c| upper(c) == 'T') {
+                if in_time {
+                    return Err(HTParseError::MalformedString);
+                }
+                in_time = true;
+                rest = after;
+                continue;
+            }
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let amount = rest[..digits].parse::<u128>().map_err(|_| HTParseError::MalformedString)?;
+            let unit = match rest[digits..].chars().next().map(upper) {
+                Some('D') if !in_time || options.allow_any_unit_order => 0,
+                Some('S') if in_time || options.allow_any_unit_order => 1,
+                Some('R') if in_time || options.allow_any_unit_order => 2,
+                _ => return Err(HTParseError::MalformedString),
+            };
+            if amounts[unit].is_some() || (!options.allow_any_unit_order && last.is_some_and(|last| last > unit)) {
+                return Err(HTParseError::MalformedString);
+            }
+            amounts[unit] = Some(amount);
+            last = Some(unit);
+            rest = &rest[digits + 1..];
+        }
+        if last.is_none() || (in_time && amounts[1].is_none() && amounts[2].is_none()) {
+            return Err(HTParseError::MalformedString);
+        }
+        let [days, sks, rem] = amounts.map(|amount| amount.unwrap_or(0));
+        let ticks = days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|ticks| ticks.checked_add(sks.checked_mul(SECONDS_PER_SKS)?))
+            .and_then(|ticks| ticks.checked_add(rem))
+            .and_then(|ticks| i128::try_from(ticks).ok())
+            .ok_or(HTParseError::OtherwiseInvalidDate)?;
+        Ok(HTDuration::from_ticks(if negative { -ticks } else { ticks }))
+    }
+
+    // magnitude split into whole days, sks and remainder
+    fn parts(&self) -> (u128, u128, u128) {
+        let ticks = self.ticks.unsigned_abs();
+        let of_day = ticks % SECONDS_PER_DAY;
+        (ticks / SECONDS_PER_DAY, of_day / SECONDS_PER_SKS, of_day % SECONDS_PER_SKS)
+    }
+}
+
+// the operators panic on overflow like integer arithmetic in debug builds, the checked_ methods don't
+impl Add for HTDuration {
+    type Output = HTDuration;
+
+    fn add(self, other: HTDuration) -> HTDuration {
+        self.checked_add(other).expect("overflow when adding durations")
+    }
+}
+
+impl Sub for HTDuration {
+    type Output = HTDuration;
+
+    fn sub(self, other: HTDuration) -> HTDuration {
+        self.checked_sub(other).expect("overflow when subtracting durations")
+    }
+}
+
+impl Neg for HTDuration {
+    type Output = HTDuration;
+
+    fn neg(self) -> HTDuration {
+        self.checked_neg().expect("overflow when negating duration")
+    }
+}
+
+impl Mul<u32> for HTDuration {
+    type Output = HTDuration;
+
+    fn mul(self, factor: u32) -> HTDuration {
+        self.checked_mul(factor as i128).expect("overflow when multiplying duration")
+    }
+}
+
+impl Div<u32> for HTDuration {
+    type Output = HTDuration;
+
+    fn div(self, divisor: u32) -> HTDuration {
+        self.checked_div(divisor as i128).expect("duration divided by zero")
+    }
+}
+
+impl AddAssign for HTDuration {
+    fn add_assign(&mut self, other: HTDuration) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for HTDuration {
+    fn sub_assign(&mut self, other: HTDuration) {
+        *self = *self - other;
+    }
+}
+
+// P3DT12S0500R normally, "3 days, 12 sks, 500 rem" with {:#}
+impl Display for HTDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (days, sks, rem) = self.parts();
+        let sign = if self.is_negative() { "-" } else { "" };
+        if !f.alternate() {
+            return write!(f, "{}P{}DT{}S{:0>4}R", sign, days, sks, rem);
+        }
+        let units = [(days, "day", "days"), (sks, "sks", "sks"), (rem, "rem", "rem")];
+        let mut first = true;
+        f.write_str(sign)?;
+        for (amount, singular, plural) in units.iter().filter(|(amount, ..)| *amount > 0) {
+            if !first {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {}", amount, if *amount == 1 { singular } else { plural })?;
+            first = false;
+        }
+        if first {
+            f.write_str("0 rem")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators_match_checked_methods() {
+        let day = HTDuration::from_days(1);
+        assert_eq!(day, HTDuration::from_ticks(SECONDS_PER_DAY as i128));
+        assert_eq!(day * 3, HTDuration::from_days(3));
+        assert_eq!(day / 4, HTDuration::from_sks(25));
+        assert_eq!(HTDuration::from_ticks(-7) / 2, HTDuration::from_ticks(-3));
+        assert_eq!(day - HTDuration::from_sks(1) + HTDuration::from_sks(1), day);
+        assert_eq!(-day, HTDuration::from_days(-1));
+        assert!(HTDuration::from_days(-1) < HTDuration::ZERO && HTDuration::ZERO < HTDuration::from_sks(1));
+        assert_eq!(day.checked_div(0), None);
+        assert_eq!(HTDuration::from_ticks(i128::MAX).checked_mul(2), None);
+    }
+
+    #[test]
+    fn displays_machine_and_human_forms() {
+        let duration = HTDuration::from_ticks(3 * SECONDS_PER_DAY as i128 + 12 * 6000 + 500);
+        assert_eq!(duration.to_string(), "P3DT12S0500R");
+        assert_eq!(format!("{:#}", duration), "3 days, 12 sks, 500 rem");
+        let negative = HTDuration::from_ticks(-(SECONDS_PER_DAY as i128) - 1);
+        assert_eq!(negative.to_string(), "-P1DT0S0001R");
+        assert_eq!(format!("{:#}", negative), "-1 day, 1 rem");
+        assert_eq!(HTDuration::ZERO.to_string(), "P0DT0S0000R");
+        assert_eq!(format!("{:#}", HTDuration::ZERO), "0 rem");
+    }
+
+    #[test]
+    fn parses_its_own_strings() {
+        for ticks in [0, 1, 12 * 6000 + 500, 3 * SECONDS_PER_DAY as i128 + 12 * 6000 + 500, -(SECONDS_PER_DAY as i128) - 1] {
+            let duration = HTDuration::from_ticks(ticks);
+            assert_eq!(HTDuration::interpret_string_with(&duration.to_string(), &HTParseOptions::STRICT), Ok(duration));
+        }
+        assert_eq!(HTDuration::interpret_string_with("P3D", &HTParseOptions::STRICT), Ok(HTDuration::from_ticks(3 * SECONDS_PER_DAY as i128)));
+        assert_eq!(HTDuration::interpret_string_with("PT5S", &HTParseOptions::STRICT), Ok(HTDuration::from_ticks(5 * 6000)));
+    }
+
+    #[test]
+    fn strict_durations_keep_unit_order() {
+        let expected = Ok(HTDuration::from_ticks(3 * SECONDS_PER_DAY as i128 + 5 * 6000));
+        assert_eq!(HTDuration::interpret_string_with("P3DT5S", &HTParseOptions::STRICT), expected);
+        assert_eq!(HTDuration::interpret_string_with("T5S 3D", &HTParseOptions::STRICT), Err(HTParseError::MalformedString));
+        assert_eq!(HTDuration::interpret_string_with("PT5S3D", &HTParseOptions::STRICT), Err(HTParseError::MalformedString));
+        assert_eq!(HTDuration::interpret_string_with("PT0500R5S", &HTParseOptions::STRICT), Err(HTParseError::MalformedString));
+        assert_eq!(HTDuration::interpret_string_with("P3DT", &HTParseOptions::STRICT), Err(HTParseError::MalformedString));
+        assert_eq!(HTDuration::interpret_string_with("P", &HTParseOptions::STRICT), Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    fn lenient_durations_take_any_unit_order() {
+        let expected = Ok(HTDuration::from_ticks(3 * SECONDS_PER_DAY as i128 + 5 * 6000));
+        assert_eq!(HTDuration::interpret_string("T5S 3D"), expected);
+        assert_eq!(HTDuration::interpret_string("3DT5S"), expected);
+        assert_eq!(HTDuration::interpret_string("5s 3d"), expected);
+        assert_eq!(HTDuration::interpret_string("-500R 1D"), Ok(HTDuration::from_ticks(-(SECONDS_PER_DAY as i128) - 500)));
+        assert_eq!(HTDuration::interpret_string("3D 5S 1D"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDuration::interpret_string("  "), Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    fn builds_and_combines_durations() {
+        let duration = HTDuration::from_days(3) + HTDuration::from_sks(12) + HTDuration::from_ticks(500);
+        assert_eq!(duration.to_string(), "P3DT12S0500R");
+        assert_eq!(duration.whole_days(), 3);
+        assert_eq!(duration.whole_sks(), 3 * 100 + 12);
+        assert_eq!((-duration).whole_days(), -3);
+        assert_eq!(duration - HTDuration::from_days(3), HTDuration::from_ticks(12 * 6000 + 500));
+        let mut total = HTDuration::ZERO;
+        total += HTDuration::from_sks(2);
+        total -= HTDuration::from_sks(5);
+        assert_eq!(total, HTDuration::from_sks(-3));
+        assert!(HTDuration::from_days(1) > HTDuration::from_sks(99));
+        assert_eq!(HTDuration::from_days(1).checked_mul(3), Some(HTDuration::from_days(3)));
+        assert_eq!(HTDuration::from_ticks(i128::MAX).checked_add(HTDuration::from_ticks(1)), None);
+        assert_eq!(HTDuration::from_ticks(i128::MIN).checked_abs(), None);
+        assert!(HTDuration::ZERO.is_zero());
+    }
+}
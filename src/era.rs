@@ -0,0 +1,86 @@
+use crate::HTDate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    BeforeZero,
+    AfterZero,
+}
+
+// where a setting's eras turn over. HT years never go below 0, so the turning point is some
+// later year picked by the setting: that year is year 1 AfterZero and the one before it year 1
+// BeforeZero, with no year 0 in between, the way AD and BC count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HTEraConvention {
+    pub zero: u128,
+}
+
+impl HTEraConvention {
+    pub fn new(zero: u128) -> Self {
+        HTEraConvention { zero }
+    }
+
+    // the era and the year within it, counting up from 1 in either direction. None for the last
+    // HT year when zero is 0, which has no year of era that fits
+    pub fn year_of_era(&self, year: u128) -> Option<(Era, u128)> {
+        if year >= self.zero {
+            Some((Era::AfterZero, (year - self.zero).checked_add(1)?))
+        } else {
+            Some((Era::BeforeZero, self.zero - year))
+        }
+    }
+
+    // the HT year, None for year 0 of an era or years before HT year 0 or past the last one
+    pub fn to_year(&self, era: Era, year_of_era: u128) -> Option<u128> {
+        match (era, year_of_era) {
+            (_, 0) => None,
+            (Era::AfterZero, year) => self.zero.checked_add(year - 1),
+            (Era::BeforeZero, year) => self.zero.checked_sub(year),
+        }
+    }
+}
+
+impl HTDate {
+    pub fn year_of_era(&self, convention: &HTEraConvention) -> Option<(Era, u128)> {
+        convention.year_of_era(self.year)
+    }
+
+    pub fn era(&self, convention: &HTEraConvention) -> Era {
+        if self.year >= convention.zero {
+            Era::AfterZero
+        } else {
+            Era::BeforeZero
+        }
+    }
+
+    // the same date in a year given by era, keeping the month, day and time
+    pub fn with_era(&self, era: Era, year_of_era: u128, convention: &HTEraConvention) -> Option<Self> {
+        Some(HTDate {
+            year: convention.to_year(era, year_of_era)?,
+            ..*self
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn counts_years_both_ways() {
+        let convention = HTEraConvention::new(2000);
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        assert_eq!(date.year_of_era(&convention), Some((Era::AfterZero, 20)));
+        assert_eq!(date.with_year(2000).unwrap().year_of_era(&convention), Some((Era::AfterZero, 1)));
+        assert_eq!(date.with_year(1999).unwrap().year_of_era(&convention), Some((Era::BeforeZero, 1)));
+        assert_eq!(date.with_era(Era::BeforeZero, 50, &convention).map(|d| d.year), Some(1950));
+        assert_eq!(date.with_era(Era::BeforeZero, 2001, &convention), None);
+        assert_eq!(date.with_era(Era::AfterZero, 0, &convention), None);
+        assert_eq!(date.era(&convention), Era::AfterZero);
+        for year in [0, 1, 1999, 2000, 2001] {
+            let (era, year_of_era) = convention.year_of_era(year).unwrap();
+            assert_eq!(convention.to_year(era, year_of_era), Some(year));
+        }
+        assert_eq!(HTEraConvention::new(0).year_of_era(u128::MAX), None);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{HTDate, HTParseError, Month, MonthStatus, DAYS_PER_MONTH, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+// checked wrappers for the numeric parts of a date, so a day can't be passed where an sks count
+// goes. each is range checked on the way in; the day against the longest month
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Year(pub u128);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sks(u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Remainder(u16);
+
+impl From<u128> for Year {
+    fn from(year: u128) -> Self {
+        Year(year)
+    }
+}
+
+impl From<Year> for u128 {
+    fn from(year: Year) -> Self {
+        year.0
+    }
+}
+
+impl TryFrom<u8> for Day {
+    type Error = HTParseError;
+
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
+        match day {
+            0 => Err(HTParseError::OtherwiseInvalidDate),
+            day if day > DAYS_PER_MONTH => Err(HTParseError::TooManyDays),
+            day => Ok(Day(day)),
+        }
+    }
+}
+
+impl From<Day> for u8 {
+    fn from(day: Day) -> Self {
+        day.0
+    }
+}
+
+impl TryFrom<u128> for Sks {
+    type Error = HTParseError;
+
+    fn try_from(sks: u128) -> Result<Self, Self::Error> {
+        if sks >= SECONDS_PER_DAY / SECONDS_PER_SKS {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(Sks(sks as u8))
+    }
+}
+
+impl From<Sks> for u128 {
+    fn from(sks: Sks) -> Self {
+        sks.0 as u128
+    }
+}
+
+impl TryFrom<u128> for Remainder {
+    type Error = HTParseError;
+
+    fn try_from(rem: u128) -> Result<Self, Self::Error> {
+        if rem >= SECONDS_PER_SKS {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(Remainder(rem as u16))
+    }
+}
+
+impl From<Remainder> for u128 {
+    fn from(rem: Remainder) -> Self {
+        rem.0 as u128
+    }
+}
+
+impl HTDate {
+    // Err only when the day is past the end of this particular month
+    pub fn from_fields(year: Year, month_status: MonthStatus, month: Month, day: Day, sks: Sks, rem: Remainder) -> Result<Self, HTParseError> {
+        let second = u128::from(sks) * SECONDS_PER_SKS + u128::from(rem);
+        HTDate::new_checked(year.into(), month_status, month, day.into(), second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_ranges() {
+        assert_eq!(Day::try_from(24).map(u8::from), Ok(24));
+        assert_eq!(Day::try_from(25), Err(HTParseError::TooManyDays));
+        assert_eq!(Day::try_from(0), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(Sks::try_from(99).map(u128::from), Ok(99));
+        assert_eq!(Sks::try_from(100), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(Remainder::try_from(5999).map(u128::from), Ok(5999));
+        assert_eq!(Remainder::try_from(6000), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(u128::from(Year::from(2019)), 2019);
+    }
+
+    #[test]
+    fn builds_dates_from_fields() {
+        let date = HTDate::from_fields(Year(2019), MonthStatus::Greater, Month::Apress, Day::try_from(1).unwrap(), Sks::try_from(31).unwrap(), Remainder::try_from(2000).unwrap());
+        assert_eq!(date, Ok(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000)));
+    }
+}
@@ -0,0 +1,577 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter};
+
+use crate::calendar::{HTCalendar, StandardCalendar};
+use crate::{month_from_letter, month_letter, status_from_letter, status_letter, HTDate, HTParseError, Month, MonthStatus, SECONDS_PER_SKS};
+
+// pattern syntax:
+// %Y year, %G month status letter, %M month letter, %D day, %S sks, %R remainder, %% a literal %
+// a dash after the percent (%-D, %-S, %-R) drops the zero padding, everything else is literal
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Padding {
+    Zero,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormatItem {
+    Year,
+    StatusLetter,
+    MonthLetter,
+    Day(Padding),
+    Sks(Padding),
+    Remainder(Padding),
+    Literal(String),
+}
+
+// the date fields a format item can carry, coarsest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Field {
+    Year,
+    MonthStatus,
+    Month,
+    Day,
+    Sks,
+    Remainder,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormatLint {
+    // the same field appears more than once, index of the repeat
+    DuplicateField { field: Field, index: usize },
+    // a field is present but a coarser one it depends on isn't, so the string can't be read back
+    MissingField { field: Field, required_by: Field },
+    // run of directly adjacent numbers (start..end item indices) with more than one variable-width number
+    AmbiguousNumbers { start: usize, end: usize },
+    // literal next to a number that starts or ends with a digit, index of the literal
+    DigitLiteral { index: usize },
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatDescription {
+    items: Vec<FormatItem>,
+}
+
+impl Field {
+    pub const ALL: [Field; 6] = [
+        Field::Year,
+        Field::MonthStatus,
+        Field::Month,
+        Field::Day,
+        Field::Sks,
+        Field::Remainder,
+    ];
+}
+
+impl FormatItem {
+    pub fn field(&self) -> Option<Field> {
+        match self {
+            FormatItem::Year => Some(Field::Year),
+            FormatItem::StatusLetter => Some(Field::MonthStatus),
+            FormatItem::MonthLetter => Some(Field::Month),
+            FormatItem::Day(_) => Some(Field::Day),
+            FormatItem::Sks(_) => Some(Field::Sks),
+            FormatItem::Remainder(_) => Some(Field::Remainder),
+            FormatItem::Literal(_) => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, FormatItem::Year | FormatItem::Day(_) | FormatItem::Sks(_) | FormatItem::Remainder(_))
+    }
+
+    // (min, max) digits this item can take up when parsed
+    pub fn digit_width(&self) -> Option<(usize, usize)> {
+        match self {
+            FormatItem::Year => Some((1, 39)), // u128::MAX has 39 digits
+            FormatItem::Day(Padding::Zero) | FormatItem::Sks(Padding::Zero) => Some((2, 2)),
+            FormatItem::Day(Padding::None) | FormatItem::Sks(Padding::None) => Some((1, 2)),
+            FormatItem::Remainder(Padding::Zero) => Some((4, 4)),
+            FormatItem::Remainder(Padding::None) => Some((1, 4)),
+            _ => None,
+        }
+    }
+
+    pub fn is_variable_width(&self) -> bool {
+        matches!(self.digit_width(), Some((min, max)) if min != max)
+    }
+}
+
+impl FormatDescription {
+    pub fn new(items: Vec<FormatItem>) -> Self {
+        FormatDescription { items }
+    }
+
+    // for descriptions built in code, rejects anything lint() has a complaint about
+    pub fn validated(items: Vec<FormatItem>) -> Result<Self, HTParseError> {
+        let description = FormatDescription { items };
+        if !description.lint().is_empty() {
+            return Err(HTParseError::InvalidFormatDescription);
+        }
+        Ok(description)
+    }
+
+    pub fn parse(pattern: &str) -> Result<Self, HTParseError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let (padding, spec) = match chars.next() {
+                Some('-') => (Padding::None, chars.next()),
+                other => (Padding::Zero, other),
+            };
+            let item = match (spec, padding) {
+                (Some('%'), Padding::Zero) => {
+                    literal.push('%');
+                    continue;
+                }
+                (Some('Y'), Padding::Zero) => FormatItem::Year,
+                (Some('G'), Padding::Zero) => FormatItem::StatusLetter,
+                (Some('M'), Padding::Zero) => FormatItem::MonthLetter,
+                (Some('D'), _) => FormatItem::Day(padding),
+                (Some('S'), _) => FormatItem::Sks(padding),
+                (Some('R'), _) => FormatItem::Remainder(padding),
+                _ => return Err(HTParseError::InvalidFormatDescription),
+            };
+            if !literal.is_empty() {
+                items.push(FormatItem::Literal(core::mem::take(&mut literal)));
+            }
+            items.push(item);
+        }
+        if !literal.is_empty() {
+            items.push(FormatItem::Literal(literal));
+        }
+        Ok(FormatDescription { items })
+    }
+
+    // best guess at a description from one sample: the first run of four or more digits is the
+    // year (or the first run if there isn't one), the other digit runs are day, sks and remainder
+    // in that order, a G or L followed by a month letter becomes the month, and everything else
+    // is kept as a literal. the guess has to be able to read its own example back
+    pub fn infer_from_example(example: &str) -> Result<Self, HTParseError> {
+        const NUMBERS: [Field; 3] = [Field::Day, Field::Sks, Field::Remainder];
+        let chars: Vec<char> = example.chars().collect();
+        let run_at = |i: usize| chars[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+        let mut runs = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let run = run_at(i);
+            if run > 0 {
+                runs.push((i, run));
+            }
+            i += run.max(1);
+        }
+        let year_start = runs.iter().find(|(_, len)| *len >= 4).or(runs.first()).map(|(start, _)| *start);
+
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut next_number = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let starts_month = status_from_letter(c).is_some() && chars.get(i + 1).is_some_and(|m| month_from_letter(*m).is_some());
+            if !c.is_ascii_digit() && !starts_month {
+                literal.push(c);
+                i += 1;
+                continue;
+            }
+            if !literal.is_empty() {
+                items.push(FormatItem::Literal(core::mem::take(&mut literal)));
+            }
+            if starts_month {
+                items.push(FormatItem::StatusLetter);
+                items.push(FormatItem::MonthLetter);
+                i += 2;
+                continue;
+            }
+            let mut remaining = run_at(i);
+            if year_start == Some(i) {
+                items.push(FormatItem::Year);
+                i += remaining;
+                continue;
+            }
+            i += remaining;
+            // a run longer than the field it lands on is split into the following fields
+            while remaining > 0 {
+                let field = NUMBERS.get(next_number).ok_or(HTParseError::InvalidFormatDescription)?;
+                next_number += 1;
+                let padded = if *field == Field::Remainder { 4 } else { 2 };
+                let padding = if remaining >= padded { Padding::Zero } else { Padding::None };
+                items.push(match field {
+                    Field::Day => FormatItem::Day(padding),
+                    Field::Sks => FormatItem::Sks(padding),
+                    _ => FormatItem::Remainder(padding),
+                });
+                remaining -= remaining.min(padded);
+            }
+        }
+        if !literal.is_empty() {
+            items.push(FormatItem::Literal(literal));
+        }
+        let description = FormatDescription { items };
+        description.interpret(example).map_err(|_| HTParseError::InvalidFormatDescription)?;
+        Ok(description)
+    }
+
+    pub fn items(&self) -> &[FormatItem] {
+        &self.items
+    }
+
+    pub fn lint(&self) -> Vec<FormatLint> {
+        let mut lints = Vec::new();
+        if self.items.is_empty() {
+            lints.push(FormatLint::Empty);
+            return lints;
+        }
+
+        let mut seen = Vec::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if let Some(field) = item.field() {
+                if seen.contains(&field) {
+                    lints.push(FormatLint::DuplicateField { field, index });
+                } else {
+                    seen.push(field);
+                }
+            }
+        }
+
+        if let Some(finest) = seen.iter().copied().max() {
+            for field in Field::ALL.iter().copied().filter(|f| *f < finest) {
+                if !seen.contains(&field) {
+                    lints.push(FormatLint::MissingField { field, required_by: finest });
+                }
+            }
+        }
+
+        let mut start = 0;
+        while start < self.items.len() {
+            let end = self.items[start..].iter().position(|i| !i.is_numeric()).map_or(self.items.len(), |p| start + p);
+            if end > start {
+                if self.items[start..end].iter().filter(|i| i.is_variable_width()).count() > 1 {
+                    lints.push(FormatLint::AmbiguousNumbers { start, end });
+                }
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+
+        for (index, item) in self.items.iter().enumerate() {
+            if let FormatItem::Literal(literal) = item {
+                let after_number = index > 0 && self.items[index - 1].is_numeric();
+                let before_number = self.items.get(index + 1).is_some_and(|i| i.is_numeric());
+                let starts_with_digit = literal.starts_with(|c: char| c.is_ascii_digit());
+                let ends_with_digit = literal.ends_with(|c: char| c.is_ascii_digit());
+                if (after_number && starts_with_digit) || (before_number && ends_with_digit) {
+                    lints.push(FormatLint::DigitLiteral { index });
+                }
+            }
+        }
+
+        lints
+    }
+
+    // numbers that sit directly next to each other are split by width; if the digits could be
+    // split more than one way the match is reported as ambiguous instead of guessing
+    pub fn interpret(&self, input: &str) -> Result<HTDate, HTParseError> {
+        self.interpret_in(input, &StandardCalendar)
+    }
+
+    // the same, with the day checked against a homebrew calendar's months and lengths
+    pub fn interpret_in<C: HTCalendar + ?Sized>(&self, input: &str, calendar: &C) -> Result<HTDate, HTParseError> {
+        let bytes = input.as_bytes();
+        let mut year = 0;
+        let mut status = MonthStatus::Greater;
+        let mut month = Month::Zero;
+        let mut day = 1;
+        let mut sks: u128 = 0;
+        let mut rem = 0;
+
+        let mut pos = 0;
+        let mut index = 0;
+        while index < self.items.len() {
+            match &self.items[index] {
+                FormatItem::Literal(literal) => {
+                    if !input[pos..].starts_with(literal.as_str()) {
+                        return Err(HTParseError::MalformedString);
+                    }
+                    pos += literal.len();
+                    index += 1;
+                }
+                FormatItem::StatusLetter => {
+                    status = bytes.get(pos).and_then(|b| status_from_letter(*b as char)).ok_or(HTParseError::MalformedString)?;
+                    pos += 1;
+                    index += 1;
+                }
+                FormatItem::MonthLetter => {
+                    month = bytes.get(pos).and_then(|b| month_from_letter(*b as char)).ok_or(HTParseError::MalformedString)?;
+                    pos += 1;
+                    index += 1;
+                }
+                _ => {
+                    let end = self.items[index..].iter().position(|i| !i.is_numeric()).map_or(self.items.len(), |p| index + p);
+                    let group = &self.items[index..end];
+                    let mut digits = bytes[pos..].iter().take_while(|b| b.is_ascii_digit()).count();
+                    // leave any leading digits of the next literal to the literal
+                    if let Some(FormatItem::Literal(literal)) = self.items.get(end) {
+                        digits = digits.saturating_sub(literal.bytes().take_while(|b| b.is_ascii_digit()).count());
+                    }
+                    let widths: Vec<(usize, usize)> = group.iter().filter_map(|i| i.digit_width()).collect();
+                    let split = split_digits(&widths, digits)?;
+                    for (item, width) in group.iter().zip(split) {
+                        let digits = &input[pos..pos + width];
+                        match item {
+                            FormatItem::Year => year = digits.parse().map_err(|_| HTParseError::MalformedString)?,
+                            FormatItem::Day(_) => day = digits.parse().map_err(|_| HTParseError::MalformedString)?,
+                            FormatItem::Sks(_) => sks = digits.parse().map_err(|_| HTParseError::MalformedString)?,
+                            FormatItem::Remainder(_) => rem = digits.parse().map_err(|_| HTParseError::MalformedString)?,
+                            _ => unreachable!(),
+                        }
+                        pos += width;
+                    }
+                    index = end;
+                }
+            }
+        }
+        if pos != input.len() {
+            return Err(HTParseError::MalformedString);
+        }
+        let second = if rem < SECONDS_PER_SKS { sks.checked_mul(SECONDS_PER_SKS) } else { None };
+        HTDate {
+            year,
+            month: (status, month),
+            day,
+            // a remainder past its sks, or an sks count too big to multiply, is past the end of the
+            // day and rejected with the other out of range times
+            second: second.map_or(u128::MAX, |second| second + rem),
+        }
+        .validated_in(calendar)
+    }
+
+    pub fn write<W: core::fmt::Write>(&self, date: &HTDate, writer: &mut W) -> core::fmt::Result {
+        for item in &self.items {
+            match item {
+                FormatItem::Year => write!(writer, "{:0>4}", date.year)?,
+                FormatItem::StatusLetter => writer.write_char(status_letter(date.month.0))?,
+                FormatItem::MonthLetter => writer.write_char(month_letter(date.month.1))?,
+                FormatItem::Day(Padding::Zero) => write!(writer, "{:0>2}", date.day)?,
+                FormatItem::Day(Padding::None) => write!(writer, "{}", date.day)?,
+                FormatItem::Sks(Padding::Zero) => write!(writer, "{:0>2}", date.second / SECONDS_PER_SKS)?,
+                FormatItem::Sks(Padding::None) => write!(writer, "{}", date.second / SECONDS_PER_SKS)?,
+                FormatItem::Remainder(Padding::Zero) => write!(writer, "{:0>4}", date.second % SECONDS_PER_SKS)?,
+                FormatItem::Remainder(Padding::None) => write!(writer, "{}", date.second % SECONDS_PER_SKS)?,
+                FormatItem::Literal(literal) => writer.write_str(literal)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn format(&self, date: &HTDate) -> String {
+        let mut string = String::new();
+        // writing to a String can't fail
+        let _ = self.write(date, &mut string);
+        string
+    }
+}
+
+// renders the description back into pattern syntax
+impl Display for FormatDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for item in &self.items {
+            match item {
+                FormatItem::Year => f.write_str("%Y")?,
+                FormatItem::StatusLetter => f.write_str("%G")?,
+                FormatItem::MonthLetter => f.write_str("%M")?,
+                FormatItem::Day(Padding::Zero) => f.write_str("%D")?,
+                FormatItem::Day(Padding::None) => f.write_str("%-D")?,
+                FormatItem::Sks(Padding::Zero) => f.write_str("%S")?,
+                FormatItem::Sks(Padding::None) => f.write_str("%-S")?,
+                FormatItem::Remainder(Padding::Zero) => f.write_str("%R")?,
+                FormatItem::Remainder(Padding::None) => f.write_str("%-R")?,
+                FormatItem::Literal(literal) => f.write_str(&literal.replace('%', "%%"))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// finds the only way to give each number a width within its bounds so they add up to `total`
+fn split_digits(widths: &[(usize, usize)], total: usize) -> Result<Vec<usize>, HTParseError> {
+    fn search(widths: &[(usize, usize)], total: usize, current: &mut Vec<usize>, found: &mut Vec<Vec<usize>>) {
+        if found.len() > 1 {
+            return;
+        }
+        match widths.split_first() {
+            None => {
+                if total == 0 {
+                    found.push(current.clone());
+                }
+            }
+            Some((&(min, max), rest)) => {
+                for width in min..=max.min(total) {
+                    current.push(width);
+                    search(rest, total - width, current, found);
+                    current.pop();
+                }
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    search(widths, total, &mut Vec::new(), &mut found);
+    match found.len() {
+        0 => Err(HTParseError::MalformedString),
+        1 => Ok(found.remove(0)),
+        _ => Err(HTParseError::AmbiguousMatch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_canonical_pattern() {
+        let desc = FormatDescription::parse("%Y-%G%M-%DT%SS%RR").unwrap();
+        assert_eq!(desc.items(), &[
+            FormatItem::Year,
+            FormatItem::Literal("-".to_string()),
+            FormatItem::StatusLetter,
+            FormatItem::MonthLetter,
+            FormatItem::Literal("-".to_string()),
+            FormatItem::Day(Padding::Zero),
+            FormatItem::Literal("T".to_string()),
+            FormatItem::Sks(Padding::Zero),
+            FormatItem::Literal("S".to_string()),
+            FormatItem::Remainder(Padding::Zero),
+            FormatItem::Literal("R".to_string()),
+        ]);
+        assert!(desc.lint().is_empty());
+    }
+
+    #[test]
+    fn rejects_bad_patterns() {
+        assert!(FormatDescription::parse("%Q").is_err());
+        assert!(FormatDescription::parse("%-Y").is_err());
+        assert!(FormatDescription::parse("%Y%").is_err());
+        assert_eq!(FormatDescription::parse("100%%").unwrap().items(), &[FormatItem::Literal("100%".to_string())]);
+    }
+
+    #[test]
+    fn lints_missing_and_duplicate_fields() {
+        let lints = FormatDescription::parse("%Y-%D").unwrap().lint();
+        assert_eq!(lints, vec![
+            FormatLint::MissingField { field: Field::MonthStatus, required_by: Field::Day },
+            FormatLint::MissingField { field: Field::Month, required_by: Field::Day },
+        ]);
+        let lints = FormatDescription::parse("%Y%G%M%D %Y").unwrap().lint();
+        assert_eq!(lints, vec![FormatLint::DuplicateField { field: Field::Year, index: 5 }]);
+    }
+
+    #[test]
+    fn lints_ambiguous_numbers() {
+        let lints = FormatDescription::parse("%Y%-D%G%M").unwrap().lint();
+        assert_eq!(lints, vec![FormatLint::AmbiguousNumbers { start: 0, end: 2 }]);
+        // a fixed-width neighbour can always be split off
+        assert!(FormatDescription::parse("%Y%G%M%D%S").unwrap().lint().is_empty());
+        let lints = FormatDescription::parse("%Y1%G%M").unwrap().lint();
+        assert_eq!(lints, vec![FormatLint::DigitLiteral { index: 1 }]);
+        assert_eq!(FormatDescription::parse("").unwrap().lint(), vec![FormatLint::Empty]);
+    }
+
+    #[test]
+    fn displays_as_pattern() {
+        for pattern in ["%Y-%G%M-%DT%SS%RR", "%-D/%-S 100%%"] {
+            assert_eq!(FormatDescription::parse(pattern).unwrap().to_string(), pattern);
+        }
+    }
+
+    #[test]
+    fn infers_descriptions_from_examples() {
+        let desc = FormatDescription::infer_from_example("2019/GA/01 31:2000").unwrap();
+        assert_eq!(desc.to_string(), "%Y/%G%M/%D %S:%R");
+        assert_eq!(desc.interpret("2020/LF/24 00:0001").unwrap(), HTDate::new(2020, MonthStatus::Lesser, Month::Funny, 24, 1));
+
+        let desc = FormatDescription::infer_from_example("2019-GA-01T31S2000R").unwrap();
+        assert_eq!(desc.to_string(), "%Y-%G%M-%DT%SS%RR");
+        let desc = FormatDescription::infer_from_example("GA 1, 2019 @ 312000").unwrap();
+        assert_eq!(desc.to_string(), "%G%M %-D, %Y @ %S%R");
+        assert!(FormatDescription::infer_from_example("1 2 3 4 5").is_err());
+        assert!(FormatDescription::infer_from_example("2019 GA 99").is_err());
+    }
+
+    #[test]
+    fn interprets_canonical_strings() {
+        let desc = FormatDescription::parse("%Y-%G%M-%DT%SS%RR").unwrap();
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(desc.interpret("2019-GA-01T31S2000R"), Ok(date));
+        assert_eq!(desc.interpret("2019-GA-01T31S2000"), Err(HTParseError::MalformedString));
+        assert_eq!(desc.interpret("2019-GA-25T31S2000R"), Err(HTParseError::TooManyDays));
+    }
+
+    #[test]
+    fn rejects_day_zero() {
+        let desc = FormatDescription::parse("%Y %G%M %-D").unwrap();
+        assert_eq!(desc.interpret("2019 GA 0"), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(desc.interpret("2019 GA 1").map(|d| d.day), Ok(1));
+        let padded = FormatDescription::parse("%Y-%G%M-%D").unwrap();
+        assert_eq!(padded.interpret("2019-GA-00"), Err(HTParseError::OtherwiseInvalidDate));
+    }
+
+    #[test]
+    fn interprets_variable_width_years() {
+        let desc = FormatDescription::parse("%Y%G%M%D").unwrap();
+        let date = desc.interpret("123456LF07").unwrap();
+        assert_eq!(date, HTDate::new(123456, MonthStatus::Lesser, Month::Funny, 7, 0));
+        // the year only has to leave two digits for the fixed-width day
+        let desc = FormatDescription::parse("%G%M%Y%D").unwrap();
+        assert_eq!(desc.interpret("GZ1202407").unwrap().year, 12024);
+    }
+
+    #[test]
+    fn reports_ambiguous_matches() {
+        let desc = FormatDescription::parse("%G%M %Y%-D").unwrap();
+        assert_eq!(desc.interpret("GA 20191"), Err(HTParseError::AmbiguousMatch));
+        // only one split when the unpadded day has to be a single digit
+        let desc = FormatDescription::parse("%G%M %-D%Y").unwrap();
+        assert_eq!(desc.interpret("GA 1"), Err(HTParseError::MalformedString));
+        let desc = FormatDescription::parse("%G%M %-D%-S").unwrap();
+        assert_eq!(desc.interpret("GA 123").map(|d| d.day), Err(HTParseError::AmbiguousMatch));
+        assert_eq!(desc.interpret("GA 1234").map(|d| (d.day, d.second)), Ok((12, 34 * 6000)));
+    }
+
+    #[test]
+    fn built_descriptions_format_and_parse() {
+        let desc = FormatDescription::validated(vec![
+            FormatItem::Day(Padding::None),
+            FormatItem::Literal(" ".to_string()),
+            FormatItem::StatusLetter,
+            FormatItem::MonthLetter,
+            FormatItem::Literal(" ".to_string()),
+            FormatItem::Year,
+            FormatItem::Literal(" ".to_string()),
+            FormatItem::Sks(Padding::Zero),
+            FormatItem::Literal(":".to_string()),
+            FormatItem::Remainder(Padding::Zero),
+        ])
+        .unwrap();
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 7, 31 * 6000 + 20);
+        assert_eq!(date.format_with(&desc), "7 GA 2019 31:0020");
+        assert_eq!(HTDate::interpret_with_format("7 GA 2019 31:0020", &desc), Ok(date));
+        let canonical = FormatDescription::parse("%Y-%G%M-%DT%SS%RR").unwrap();
+        assert_eq!(canonical.format(&date), date.to_string());
+    }
+
+    #[test]
+    fn validated_rejects_lints() {
+        assert_eq!(FormatDescription::validated(vec![]), Err(HTParseError::InvalidFormatDescription));
+        let duplicate = vec![FormatItem::Year, FormatItem::Literal("-".to_string()), FormatItem::Year];
+        assert_eq!(FormatDescription::validated(duplicate), Err(HTParseError::InvalidFormatDescription));
+    }
+}
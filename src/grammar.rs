@@ -0,0 +1,165 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use crate::HTFormat;
+
+// the strings interpret_string accepts, as data. the strict parser checks its input with accepts()
+// but the lenient one still reads fixed offsets, so the parser_conforms_to_spec test keeps the two
+// in step
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    Digit,
+    Status,
+    Month,
+    Literal(char),
+}
+
+// the layouts interpret_string reads, the week and ordinal forms have their own parsers
+pub const FORMATS: [HTFormat; 5] = [
+    HTFormat::Compact,
+    HTFormat::Dashed,
+    HTFormat::CompactWithTime,
+    HTFormat::DashedWithTime,
+    HTFormat::UrlSafe,
+];
+
+pub fn rule_name(format: HTFormat) -> &'static str {
+    match format {
+        HTFormat::Compact => "compact",
+        HTFormat::Dashed => "dashed",
+        HTFormat::CompactWithTime => "compact_with_time",
+        HTFormat::DashedWithTime => "dashed_with_time",
+        HTFormat::WeekDate => "week_date",
+        HTFormat::Ordinal => "ordinal",
+        HTFormat::UrlSafe => "url_safe",
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn symbols(format: HTFormat) -> Vec<Symbol> {
+    format
+        .layout()
+        .chars()
+        .map(|c| match c {
+            'Y' | 'D' | 'N' => Symbol::Digit,
+            'G' => Symbol::Status,
+            'M' => Symbol::Month,
+            c => Symbol::Literal(c),
+        })
+        .collect()
+}
+
+pub fn accepts(input: &str) -> bool {
+    FORMATS.iter().any(|format| format.matches(input))
+}
+
+// ISO 14977 EBNF, e.g. `dashed = 4 * digit, "-", status, month, "-", 2 * digit ;`
+#[cfg(feature = "alloc")]
+pub fn spec() -> String {
+    let names: Vec<&str> = FORMATS.iter().map(|format| rule_name(*format)).collect();
+    let mut spec = format!("date = {} ;\n", names.join(" | "));
+    for format in FORMATS {
+        let mut terms: Vec<String> = Vec::new();
+        let mut digits = 0;
+        for symbol in symbols(format) {
+            if symbol == Symbol::Digit {
+                digits += 1;
+                continue;
+            }
+            if digits > 0 {
+                terms.push(format!("{} * digit", digits));
+                digits = 0;
+            }
+            terms.push(match symbol {
+                Symbol::Status => "status".to_string(),
+                Symbol::Month => "month".to_string(),
+                Symbol::Literal(c) => format!("\"{}\"", c),
+                Symbol::Digit => unreachable!(),
+            });
+        }
+        if digits > 0 {
+            terms.push(format!("{} * digit", digits));
+        }
+        spec.push_str(&format!("{} = {} ;\n", rule_name(format), terms.join(", ")));
+    }
+    spec.push_str("status = \"G\" | \"L\" ;\n");
+    spec.push_str("month = \"Z\" | \"N\" | \"A\" | \"S\" | \"F\" ;\n");
+    spec.push_str("digit = \"0\" | \"1\" | \"2\" | \"3\" | \"4\" | \"5\" | \"6\" | \"7\" | \"8\" | \"9\" ;\n");
+    spec
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::{HTDate, HTParseError, HTParseOptions};
+    use std::collections::HashMap;
+
+    // a recognizer for just the subset of EBNF spec() writes, so the test reads the spec as text
+    // the way an outside implementation would
+    fn recognize(rules: &HashMap<&str, Vec<Vec<&str>>>, rule: &str, input: &str) -> Vec<usize> {
+        let mut ends = Vec::new();
+        for alternative in &rules[rule] {
+            let mut positions = vec![0];
+            for term in alternative {
+                let (count, term) = match term.split_once(" * ") {
+                    Some((count, term)) => (count.parse().unwrap(), term),
+                    None => (1, *term),
+                };
+                for _ in 0..count {
+                    positions = positions
+                        .iter()
+                        .flat_map(|start| match term.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+                            Some(literal) => input[*start..].starts_with(literal).then(|| start + literal.len()).into_iter().collect(),
+                            None => recognize(rules, term, &input[*start..]).into_iter().map(|end| start + end).collect::<Vec<_>>(),
+                        })
+                        .collect();
+                }
+            }
+            ends.extend(positions);
+        }
+        ends
+    }
+
+    fn spec_accepts(spec: &str, input: &str) -> bool {
+        let rules: HashMap<&str, Vec<Vec<&str>>> = spec
+            .lines()
+            .map(|line| {
+                let (name, body) = line.trim_end_matches(" ;").split_once(" = ").unwrap();
+                (name, body.split(" | ").map(|alternative| alternative.split(", ").collect()).collect())
+            })
+            .collect();
+        recognize(&rules, "date", input).contains(&input.len())
+    }
+
+    #[test]
+    fn writes_ebnf() {
+        let spec = spec();
+        assert!(spec.starts_with("date = compact | dashed | compact_with_time | dashed_with_time | url_safe ;\n"));
+        assert!(spec.contains("\ndashed_with_time = 4 * digit, \"-\", status, month, \"-\", 2 * digit, \"T\", 2 * digit, \"S\", 4 * digit, \"R\" ;\n"));
+    }
+
+    #[test]
+    fn parser_conforms_to_spec() {
+        let spec = spec();
+        let seeds = ["2019GA01", "2019-GA-01", "2019GA01T31S2000R", "2019-GA-01T31S2000R", "2019GA01_31S2000R"];
+        let replacements = ['0', '9', 'G', 'L', 'Z', 'F', '-', 'T', 'S', 'R', '_', 'x', 'g'];
+        let mut candidates: Vec<String> = seeds.iter().map(|s| s.to_string()).collect();
+        for seed in seeds {
+            for index in 0..seed.len() {
+                candidates.push(format!("{}{}", &seed[..index], &seed[index + 1..]));
+                for c in replacements {
+                    let mut candidate = seed.to_string();
+                    candidate.replace_range(index..index + 1, &c.to_string());
+                    candidates.push(candidate);
+                }
+            }
+        }
+        for candidate in candidates {
+            let parsed = HTDate::interpret_string_with(&candidate, &HTParseOptions::STRICT);
+            // out of range days are the parser's business, not the grammar's
+            let syntactically_valid = !matches!(parsed, Err(HTParseError::MalformedString) | Err(HTParseError::UnrecognizedLayout));
+            assert_eq!(syntactically_valid, spec_accepts(&spec, &candidate), "{}", candidate);
+            assert_eq!(accepts(&candidate), spec_accepts(&spec, &candidate), "{}", candidate);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::HTDate;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+// where HT time sits on the real timeline: the unix instant of the HT epoch (year 0, Greater Zero
+// 1, zero seconds) and how long a tick lasts, both in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HTEpochMapping {
+    pub epoch_unix_nanos: i128,
+    pub nanos_per_tick: u64,
+}
+
+impl HTEpochMapping {
+    // the HT epoch at 1970-01-01T00:00:00Z with an HT day as long as an earth day, so a tick is
+    // 144ms
+    pub const DEFAULT: HTEpochMapping = HTEpochMapping {
+        epoch_unix_nanos: 0,
+        nanos_per_tick: 144_000_000,
+    };
+
+    pub const fn from_unix_seconds(epoch_unix_seconds: i64, nanos_per_tick: u64) -> Self {
+        HTEpochMapping {
+            epoch_unix_nanos: epoch_unix_seconds as i128 * NANOS_PER_SECOND,
+            nanos_per_tick,
+        }
+    }
+
+    // None for years too far out to fit i128 nanoseconds
+    pub fn to_unix_nanos(&self, date: &HTDate) -> Option<i128> {
+        let ticks = i128::try_from(date.checked_epoch()?).ok()?;
+        ticks.checked_mul(self.nanos_per_tick as i128)?.checked_add(self.epoch_unix_nanos)
+    }
+
+    // rounded down to the tick, None before the HT epoch or for a zero length tick
+    pub fn from_unix_nanos(&self, nanos: i128) -> Option<HTDate> {
+        let since_epoch = nanos.checked_sub(self.epoch_unix_nanos)?;
+        if since_epoch < 0 || self.nanos_per_tick == 0 {
+            return None;
+        }
+        Some(HTDate::from_epoch((since_epoch / self.nanos_per_tick as i128) as u128))
+    }
+
+    // whole seconds and the nanoseconds past them, the split most clock types are built from
+    pub fn to_unix_parts(&self, date: &HTDate) -> Option<(i64, u32)> {
+        let nanos = self.to_unix_nanos(date)?;
+        let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+        Some((seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32))
+    }
+
+    pub fn from_unix_parts(&self, seconds: i64, subsec_nanos: u32) -> Option<HTDate> {
+        self.from_unix_nanos(seconds as i128 * NANOS_PER_SECOND + subsec_nanos as i128)
+    }
+}
+
+impl Default for HTEpochMapping {
+    fn default() -> Self {
+        HTEpochMapping::DEFAULT
+    }
+}
+
+// stamping from the OS clock, through HTEpochMapping::DEFAULT unless a mapping is given. None
+// before the HT epoch or past what SystemTime can hold on this platform
+#[cfg(feature = "std")]
+impl HTDate {
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        HTDate::from_system_time_with(time, &HTEpochMapping::DEFAULT)
+    }
+
+    pub fn from_system_time_with(time: SystemTime, mapping: &HTEpochMapping) -> Option<Self> {
+        let nanos = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => i128::try_from(after.as_nanos()).ok()?,
+            Err(before) => -i128::try_from(before.duration().as_nanos()).ok()?,
+        };
+        mapping.from_unix_nanos(nanos)
+    }
+
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        self.to_system_time_with(&HTEpochMapping::DEFAULT)
+    }
+
+    pub fn to_system_time_with(&self, mapping: &HTEpochMapping) -> Option<SystemTime> {
+        let nanos = mapping.to_unix_nanos(self)?;
+        let since = u64::try_from(nanos.unsigned_abs() / NANOS_PER_SECOND as u128).ok()?;
+        let gap = Duration::new(since, (nanos.unsigned_abs() % NANOS_PER_SECOND as u128) as u32);
+        if nanos < 0 {
+            UNIX_EPOCH.checked_sub(gap)
+        } else {
+            UNIX_EPOCH.checked_add(gap)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus, SECONDS_PER_DAY};
+
+    #[test]
+    fn maps_ticks_to_nanoseconds() {
+        let mapping = HTEpochMapping::DEFAULT;
+        let epoch = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(mapping.to_unix_nanos(&epoch), Some(0));
+        assert_eq!(mapping.from_unix_nanos(143_999_999), Some(epoch));
+        assert_eq!(mapping.from_unix_nanos(144_000_000), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 1)));
+        // a day of ticks is 86400 seconds
+        assert_eq!(mapping.to_unix_parts(&HTDate::from_epoch(SECONDS_PER_DAY)), Some((86_400, 0)));
+        assert_eq!(mapping.from_unix_nanos(-1), None);
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        let (seconds, nanos) = mapping.to_unix_parts(&date).unwrap();
+        assert_eq!(mapping.from_unix_parts(seconds, nanos), Some(date));
+    }
+
+    #[test]
+    fn shifts_the_epoch() {
+        let mapping = HTEpochMapping::from_unix_seconds(-86_400, 144_000_000);
+        assert_eq!(mapping.from_unix_parts(0, 0), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 2, 0)));
+        assert_eq!(mapping.to_unix_parts(&HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 1)), Some((-86_400, 144_000_000)));
+        assert_eq!(HTEpochMapping { nanos_per_tick: 0, ..mapping }.from_unix_nanos(0), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_system_times() {
+        let date = HTDate::new(56, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        let time = date.to_system_time().unwrap();
+        assert_eq!(time.duration_since(UNIX_EPOCH).unwrap().as_nanos() as i128, HTEpochMapping::DEFAULT.to_unix_nanos(&date).unwrap());
+        assert_eq!(HTDate::from_system_time(time), Some(date));
+        assert_eq!(HTDate::from_system_time(UNIX_EPOCH + Duration::from_millis(289)), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 2)));
+        assert_eq!(HTDate::from_system_time(UNIX_EPOCH - Duration::from_secs(1)), None);
+        // an epoch before 1970 puts early HT dates before UNIX_EPOCH
+        let mapping = HTEpochMapping::from_unix_seconds(-86_400, 144_000_000);
+        let epoch = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(epoch.to_system_time_with(&mapping), UNIX_EPOCH.checked_sub(Duration::from_secs(86_400)));
+        assert_eq!(HTDate::from_system_time_with(UNIX_EPOCH - Duration::from_secs(86_400), &mapping), Some(epoch));
+    }
+}
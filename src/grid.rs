@@ -0,0 +1,56 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use crate::{days_in_month, month_name, status_name, Month, MonthStatus, DAYS_PER_WEEK};
+
+// a text calendar for one month, a row per week:
+//
+// Greater Apress 2019
+//   1   2   3   4   5   6
+//   7   8   9  10  11  12
+//  ...
+pub fn render_month(year: u128, status: MonthStatus, month: Month) -> String {
+    render_month_highlighting(year, status, month, None)
+}
+
+// same, with the given day in brackets, e.g. "[ 9]"
+pub fn render_month_highlighting(year: u128, status: MonthStatus, month: Month, highlight: Option<u8>) -> String {
+    let mut grid = format!("{} {} {}\n", status_name(status), month_name(month), year);
+    let length = days_in_month(year, status, month);
+    for week_start in (1..=length).step_by(DAYS_PER_WEEK as usize) {
+        let mut row = String::new();
+        for day in week_start..(week_start + DAYS_PER_WEEK).min(length + 1) {
+            // writing to a String can't fail
+            let _ = if Some(day) == highlight {
+                write!(row, "[{:>2}]", day)
+            } else {
+                write!(row, " {:>2} ", day)
+            };
+        }
+        grid.push_str(row.trim_end());
+        grid.push('\n');
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_weeks_as_rows() {
+        let grid = render_month(2019, MonthStatus::Greater, Month::Apress);
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "Greater Apress 2019");
+        assert_eq!(lines[1], "  1   2   3   4   5   6");
+        assert_eq!(lines[4], " 19  20  21  22  23  24");
+    }
+
+    #[test]
+    fn highlights_a_day() {
+        let grid = render_month_highlighting(2019, MonthStatus::Lesser, Month::Zero, Some(9));
+        assert_eq!(grid.lines().nth(2), Some("  7   8 [ 9] 10  11  12"));
+        assert_eq!(render_month_highlighting(2019, MonthStatus::Lesser, Month::Zero, Some(30)), render_month(2019, MonthStatus::Lesser, Month::Zero));
+    }
+}
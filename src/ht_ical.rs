@@ -0,0 +1,159 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use crate::{HTDate, HTFormatOptions, HTParseError};
+
+// VCALENDAR-style files of (date, summary) events, with DTSTART holding the compact HT date:
+//
+// BEGIN:VCALENDAR
+// VERSION:2.0
+// PRODID:-//ht_timeparser//EN
+// BEGIN:VEVENT
+// DTSTART;VALUE=X-HT-DATE:2019GA01T31S2000R
+// SUMMARY:moot\, second session
+// END:VEVENT
+// END:VCALENDAR
+//
+// lines end in CRLF and are folded at 75 bytes like in RFC 5545
+
+const MAX_LINE: usize = 75;
+
+pub fn to_ical(events: &[(HTDate, String)]) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//ht_timeparser//EN");
+    for (date, summary) in events {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("DTSTART;VALUE=X-HT-DATE:{}", date.to_string_with(&HTFormatOptions::COMPACT)));
+        push_line(&mut out, &format!("SUMMARY:{}", escape(summary)));
+        push_line(&mut out, "END:VEVENT");
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+// events in file order. properties other than DTSTART and SUMMARY are skipped
+pub fn from_ical(text: &str) -> Result<Vec<(HTDate, String)>, HTParseError> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)) {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    if lines.first().map(String::as_str) != Some("BEGIN:VCALENDAR") || lines.last().map(String::as_str) != Some("END:VCALENDAR") {
+        return Err(HTParseError::MalformedString);
+    }
+    let mut events = Vec::new();
+    let mut event: Option<(Option<HTDate>, String)> = None;
+    for line in &lines[1..lines.len() - 1] {
+        let (name, value) = line.split_once(':').ok_or(HTParseError::MalformedString)?;
+        // parameters such as ;VALUE=X-HT-DATE don't change how the value is read
+        let name = name.split(';').next().unwrap_or(name);
+        match (name, value, &mut event) {
+            ("BEGIN", "VEVENT", None) => event = Some((None, String::new())),
+            ("END", "VEVENT", Some((date, summary))) => {
+                events.push((date.ok_or(HTParseError::MalformedString)?, core::mem::take(summary)));
+                event = None;
+            }
+            ("DTSTART", value, Some((date, _))) => *date = Some(HTDate::interpret_string(value)?),
+            ("SUMMARY", value, Some((_, summary))) => *summary = unescape(value)?,
+            ("BEGIN", _, _) | ("END", _, _) => return Err(HTParseError::MalformedString),
+            _ => {}
+        }
+    }
+    if event.is_some() {
+        return Err(HTParseError::MalformedString);
+    }
+    Ok(events)
+}
+
+fn push_line(out: &mut String, line: &str) {
+    let mut rest = line;
+    let mut limit = MAX_LINE;
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        out.push_str(&rest[..split]);
+        out.push_str("\r\n ");
+        rest = &rest[split..];
+        // the leading space counts towards the next line
+        limit = MAX_LINE - 1;
+    }
+    out.push_str(rest);
+    out.push_str("\r\n");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(text: &str) -> Result<String, HTParseError> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(c @ ('\\' | ';' | ',')) => unescaped.push(c),
+            _ => return Err(HTParseError::MalformedString),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn writes_vcalendar() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let text = to_ical(&[(date, "moot, second session".to_string())]);
+        assert_eq!(
+            text,
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//ht_timeparser//EN\r\nBEGIN:VEVENT\r\n\
+             DTSTART;VALUE=X-HT-DATE:2019GA01T31S2000R\r\nSUMMARY:moot\\, second session\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n"
+        );
+    }
+
+    #[test]
+    fn round_trips_events() {
+        let events = vec![
+            (HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0), "opening; bring snacks\nand chairs".to_string()),
+            (HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 24, 599_999), "é".repeat(60)),
+        ];
+        let text = to_ical(&events);
+        assert!(text.split("\r\n").all(|line| line.len() <= MAX_LINE));
+        assert_eq!(from_ical(&text), Ok(events));
+    }
+
+    #[test]
+    fn rejects_broken_files() {
+        assert_eq!(from_ical(""), Err(HTParseError::MalformedString));
+        let unclosed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:2019GA01\nEND:VCALENDAR\n";
+        assert_eq!(from_ical(unclosed), Err(HTParseError::MalformedString));
+        let undated = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:x\nEND:VEVENT\nEND:VCALENDAR\n";
+        assert_eq!(from_ical(undated), Err(HTParseError::MalformedString));
+        let lf_only = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:2019GA01\nUID:1\nEND:VEVENT\nEND:VCALENDAR\n";
+        assert_eq!(from_ical(lf_only).unwrap()[0].0, HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0));
+    }
+}
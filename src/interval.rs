@@ -0,0 +1,220 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use crate::duration::HTDuration;
+use crate::temporal::HTTemporal;
+use crate::HTDate;
+#[cfg(feature = "alloc")]
+use crate::Granularity;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HTIntervalError {
+    EndBeforeStart,
+    // moving an end past the first or last representable date
+    OutOfRange,
+}
+
+// span of time from start up to end, end is never before start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTInterval {
+    start: HTDate,
+    end: HTDate,
+}
+
+impl HTInterval {
+    pub fn new(start: HTDate, end: HTDate) -> Result<Self, HTIntervalError> {
+        if end.to_epoch() < start.to_epoch() {
+            return Err(HTIntervalError::EndBeforeStart);
+        }
+        Ok(HTInterval { start, end })
+    }
+
+    pub fn start(&self) -> HTDate {
+        self.start
+    }
+
+    pub fn end(&self) -> HTDate {
+        self.end
+    }
+
+    // None when the ends are too far apart for a duration
+    pub fn duration(&self) -> Option<HTDuration> {
+        self.end.checked_duration_since(&self.start)
+    }
+
+    // start is inside the interval and end is not, so back to back intervals don't share a date
+    pub fn contains(&self, date: &HTDate) -> bool {
+        (self.start.to_epoch()..self.end.to_epoch()).contains(&date.to_epoch())
+    }
+
+    pub fn overlaps(&self, other: &HTInterval) -> bool {
+        self.start.to_epoch() < other.end.to_epoch() && other.start.to_epoch() < self.end.to_epoch()
+    }
+
+    // None unless the two overlap
+    pub fn intersection(&self, other: &HTInterval) -> Option<HTInterval> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = if self.start.to_epoch() < other.start.to_epoch() { other.start } else { self.start };
+        let end = if self.end.to_epoch() < other.end.to_epoch() { self.end } else { other.end };
+        Some(HTInterval { start, end })
+    }
+
+    // the interval covering both, None if there would be a gap between them. intervals that only
+    // touch end to start are joined
+    pub fn union(&self, other: &HTInterval) -> Option<HTInterval> {
+        if self.start.to_epoch() > other.end.to_epoch() || other.start.to_epoch() > self.end.to_epoch() {
+            return None;
+        }
+        let start = if self.start.to_epoch() < other.start.to_epoch() { self.start } else { other.start };
+        let end = if self.end.to_epoch() < other.end.to_epoch() { other.end } else { self.end };
+        Some(HTInterval { start, end })
+    }
+
+    // joins overlapping and touching intervals, giving them back sorted by start with gaps
+    // between each. input that's already sorted by start is merged in one pass
+    #[cfg(feature = "alloc")]
+    pub fn merge(intervals: &[HTInterval]) -> Vec<HTInterval> {
+        let mut sorted = intervals.to_vec();
+        sorted.sort_by_key(|interval| interval.start.to_epoch());
+        let mut merged: Vec<HTInterval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut().and_then(|last| last.union(&interval).map(|union| (last, union))) {
+                Some((last, union)) => *last = union,
+                None => merged.push(interval),
+            }
+        }
+        merged
+    }
+
+    // moves both ends by the same amount
+    pub fn shift(&self, by: HTDuration) -> Result<Self, HTIntervalError> {
+        let start = self.start.checked_add_duration(by).ok_or(HTIntervalError::OutOfRange)?;
+        let end = self.end.checked_add_duration(by).ok_or(HTIntervalError::OutOfRange)?;
+        HTInterval::new(start, end)
+    }
+
+    // moves the end later, or earlier for a negative duration
+    pub fn extend_end(&self, by: HTDuration) -> Result<Self, HTIntervalError> {
+        let end = self.end.checked_add_duration(by).ok_or(HTIntervalError::OutOfRange)?;
+        HTInterval::new(self.start, end)
+    }
+
+    // moves the start later, or earlier for a negative duration
+    pub fn shrink_start(&self, by: HTDuration) -> Result<Self, HTIntervalError> {
+        let start = self.start.checked_add_duration(by).ok_or(HTIntervalError::OutOfRange)?;
+        HTInterval::new(start, self.end)
+    }
+
+    // n dates from start to end inclusive with equal gaps, rounded down to the tick
+    #[cfg(feature = "alloc")]
+    pub fn evenly_spaced(&self, n: usize) -> Vec<HTDate> {
+        let start = self.start.to_epoch();
+        let span = self.end.to_epoch() - start;
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.start],
+            _ => {
+                let gaps = n as u128 - 1;
+                // split up so span * i can't overflow
+                let (step, leftover) = (span / gaps, span % gaps);
+                (0..n as u128).map(|i| HTDate::from_epoch(start + step * i + leftover * i / gaps)).collect()
+            }
+        }
+    }
+
+    // same, with each date moved back to the start of its year, month, day or sks, e.g. for chart
+    // ticks. the first can land before the interval starts, and neighbours can land on the same date
+    #[cfg(feature = "alloc")]
+    pub fn evenly_spaced_aligned(&self, n: usize, granularity: Granularity) -> Vec<HTDate> {
+        let unit = granularity.ticks();
+        self.evenly_spaced(n)
+            .iter()
+            .map(|date| {
+                let ticks = date.to_epoch();
+                HTDate::from_epoch(ticks - ticks % unit)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+    use crate::SECONDS_PER_DAY;
+
+    fn date(day: u8, second: u128) -> HTDate {
+        HTDate::new(2019, MonthStatus::Greater, Month::Apress, day, second)
+    }
+
+    #[test]
+    fn validates_order() {
+        assert!(HTInterval::new(date(1, 0), date(1, 0)).is_ok());
+        assert_eq!(HTInterval::new(date(2, 0), date(1, 0)), Err(HTIntervalError::EndBeforeStart));
+    }
+
+    #[test]
+    fn adjusts_windows() {
+        let window = HTInterval::new(date(1, 0), date(1, 6000)).unwrap();
+        let shifted = window.shift(HTDuration::from_ticks(-1000)).unwrap();
+        // crosses back into the last day of the previous month
+        assert_eq!(shifted.start(), HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 24, SECONDS_PER_DAY - 1000));
+        assert_eq!(shifted.end(), date(1, 5000));
+        assert_eq!(window.extend_end(HTDuration::from_ticks(500)).unwrap().end(), date(1, 6500));
+        assert_eq!(window.shrink_start(HTDuration::from_ticks(500)).unwrap().start(), date(1, 500));
+        assert_eq!(window.shrink_start(HTDuration::from_ticks(6001)), Err(HTIntervalError::EndBeforeStart));
+        assert_eq!(window.extend_end(HTDuration::from_ticks(-6001)), Err(HTIntervalError::EndBeforeStart));
+        let first = HTInterval::new(HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0), date(1, 0)).unwrap();
+        assert_eq!(first.shift(HTDuration::from_ticks(-1)), Err(HTIntervalError::OutOfRange));
+    }
+
+    #[test]
+    fn compares_intervals() {
+        let morning = HTInterval::new(date(1, 0), date(1, 300_000)).unwrap();
+        let midday = HTInterval::new(date(1, 200_000), date(1, 400_000)).unwrap();
+        let evening = HTInterval::new(date(1, 400_000), date(2, 0)).unwrap();
+        assert!(morning.contains(&date(1, 0)));
+        assert!(!morning.contains(&date(1, 300_000)));
+        assert!(morning.overlaps(&midday));
+        assert!(!midday.overlaps(&evening));
+        assert_eq!(morning.intersection(&midday), HTInterval::new(date(1, 200_000), date(1, 300_000)).ok());
+        assert_eq!(morning.intersection(&evening), None);
+        assert_eq!(midday.union(&evening), HTInterval::new(date(1, 200_000), date(2, 0)).ok());
+        assert_eq!(morning.union(&evening), None);
+        assert_eq!(morning.duration(), Some(HTDuration::from_ticks(300_000)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merges_interval_lists() {
+        let a = HTInterval::new(date(1, 0), date(1, 100)).unwrap();
+        let b = HTInterval::new(date(1, 50), date(1, 200)).unwrap();
+        let c = HTInterval::new(date(1, 200), date(1, 300)).unwrap();
+        let d = HTInterval::new(date(2, 0), date(2, 10)).unwrap();
+        let expected = vec![HTInterval::new(date(1, 0), date(1, 300)).unwrap(), d];
+        assert_eq!(HTInterval::merge(&[a, b, c, d]), expected);
+        assert_eq!(HTInterval::merge(&[d, c, a, b]), expected);
+        assert!(HTInterval::merge(&[]).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn spaces_dates_evenly() {
+        let window = HTInterval::new(date(1, 0), date(3, 0)).unwrap();
+        assert_eq!(window.evenly_spaced(3), vec![date(1, 0), date(2, 0), date(3, 0)]);
+        assert_eq!(window.evenly_spaced(5)[1], date(1, SECONDS_PER_DAY / 2));
+        assert_eq!(window.evenly_spaced(1), vec![date(1, 0)]);
+        assert!(window.evenly_spaced(0).is_empty());
+        let odd = HTInterval::new(date(1, 0), date(1, 10)).unwrap();
+        assert_eq!(odd.evenly_spaced(4), vec![date(1, 0), date(1, 3), date(1, 6), date(1, 10)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn aligns_spaced_dates() {
+        let window = HTInterval::new(date(1, 100), date(3, 100)).unwrap();
+        assert_eq!(window.evenly_spaced_aligned(3, Granularity::Day), vec![date(1, 0), date(2, 0), date(3, 0)]);
+        assert_eq!(window.evenly_spaced_aligned(2, Granularity::Month), vec![date(1, 0), date(1, 0)]);
+    }
+}
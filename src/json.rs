@@ -0,0 +1,71 @@
+use serde_json::{json, Value};
+
+use crate::order::{MonthExt, MonthStatusExt};
+use crate::{month_name, status_name, HTDate, HTParseError, Month, MonthStatus, SECONDS_PER_SKS};
+
+// {"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": 31, "rem": 2000},
+// for consumers that shouldn't have to parse the string form
+impl HTDate {
+    // years past u64::MAX don't fit a json number
+    pub fn to_json_value(&self) -> Result<Value, HTParseError> {
+        let year = u64::try_from(self.year).map_err(|_| HTParseError::OtherwiseInvalidDate)?;
+        let sks = u64::try_from(self.second / SECONDS_PER_SKS).map_err(|_| HTParseError::OtherwiseInvalidDate)?;
+        Ok(json!({
+            "year": year,
+            "monthStatus": status_name(self.month.0),
+            "month": month_name(self.month.1),
+            "day": self.day,
+            "sks": sks,
+            "rem": (self.second % SECONDS_PER_SKS) as u64,
+        }))
+    }
+
+    pub fn from_json_value(value: &Value) -> Result<Self, HTParseError> {
+        let number = |key: &str| value.get(key).and_then(Value::as_u64).ok_or(HTParseError::MalformedString);
+        let name = |key: &str| value.get(key).and_then(Value::as_str).ok_or(HTParseError::MalformedString);
+        let status_text = name("monthStatus")?;
+        let month_text = name("month")?;
+        let status = MonthStatus::ORDER.into_iter().find(|s| status_name(*s) == status_text).ok_or(HTParseError::MalformedString)?;
+        let month = Month::ORDER.into_iter().find(|m| month_name(*m) == month_text).ok_or(HTParseError::MalformedString)?;
+        let day = u8::try_from(number("day")?).map_err(|_| HTParseError::TooManyDays)?;
+        let rem = number("rem")? as u128;
+        if rem >= SECONDS_PER_SKS {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        let sks = (number("sks")? as u128).checked_mul(SECONDS_PER_SKS).ok_or(HTParseError::OtherwiseInvalidDate)?;
+        // new_checked catches day 0, days past the month and sks past the end of the day
+        HTDate::new_checked(number("year")? as u128, status, month, day, sks + rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_json_objects() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let value = date.to_json_value().unwrap();
+        assert_eq!(value, json!({"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": 31, "rem": 2000}));
+        assert_eq!(HTDate::from_json_value(&value), Ok(date));
+    }
+
+    #[test]
+    fn rejects_bad_objects() {
+        let bad_month = json!({"year": 2019, "monthStatus": "Greater", "month": "April", "day": 1, "sks": 0, "rem": 0});
+        assert_eq!(HTDate::from_json_value(&bad_month), Err(HTParseError::MalformedString));
+        let bad_day = json!({"year": 2019, "monthStatus": "Lesser", "month": "Zero", "day": 25, "sks": 0, "rem": 0});
+        assert_eq!(HTDate::from_json_value(&bad_day), Err(HTParseError::TooManyDays));
+        let day_zero = json!({"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 0, "sks": 0, "rem": 0});
+        assert_eq!(HTDate::from_json_value(&day_zero), Err(HTParseError::OtherwiseInvalidDate));
+        let late = json!({"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": 100, "rem": 0});
+        assert_eq!(HTDate::from_json_value(&late), Err(HTParseError::OtherwiseInvalidDate));
+        let huge_sks = json!({"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": u64::MAX, "rem": 0});
+        assert_eq!(HTDate::from_json_value(&huge_sks), Err(HTParseError::OtherwiseInvalidDate));
+        let rem = json!({"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": 0, "rem": 6000});
+        assert_eq!(HTDate::from_json_value(&rem), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::from_json_value(&json!("2019-GA-01")), Err(HTParseError::MalformedString));
+        let huge = HTDate::new(u128::MAX, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(huge.to_json_value(), Err(HTParseError::OtherwiseInvalidDate));
+    }
+}
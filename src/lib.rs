@@ -1,8 +1,156 @@
-use std::fmt::{Display, Formatter};
-use std::num::Wrapping;
-use ht_cal::datetime::{HDateTime, Month, MonthStatus};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use core::fmt::{Display, Formatter};
+use crate::calendar::{HTCalendar, StandardCalendar};
+use crate::duration::HTDuration;
+#[cfg(feature = "alloc")]
+use crate::format::FormatDescription;
+#[cfg(feature = "alloc")]
+use crate::locale::HTLocale;
+#[cfg(feature = "ht_cal")]
+use ht_cal::datetime::HDateTime;
+
+// without ht_cal (or with the standalone feature) the month enums are this crate's own copies
+#[cfg(not(any(feature = "standalone", not(feature = "ht_cal"))))]
+pub use ht_cal::datetime::{Month, MonthStatus};
+#[cfg(any(feature = "standalone", not(feature = "ht_cal")))]
+pub use months::{Month, MonthStatus};
+pub use order::{MonthExt, MonthPairExt, MonthStatusExt};
+
+pub mod breakdown;
+pub mod builder;
+pub mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono_interop;
+pub mod clock;
+pub mod duration;
+pub mod era;
+pub mod fields;
+#[cfg(feature = "alloc")]
+pub mod format;
+pub mod grammar;
+pub mod gregorian;
+#[cfg(feature = "alloc")]
+pub mod grid;
+#[cfg(feature = "alloc")]
+pub mod ht_ical;
+pub mod interval;
+mod literal;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "alloc")]
+pub mod locale;
+#[cfg(any(feature = "standalone", not(feature = "ht_cal")))]
+pub mod months;
+pub mod offset;
+pub mod order;
+pub mod partial;
+pub mod period;
+pub mod range;
+#[cfg(feature = "alloc")]
+pub mod relative;
+pub mod season;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "alloc")]
+pub mod syslog;
+pub mod temporal;
+#[cfg(feature = "time")]
+mod time_interop;
+pub mod time_of_day;
+pub mod timestamp;
+pub mod ulid;
+pub mod week;
+#[cfg(feature = "alloc")]
+pub mod working_days;
+#[cfg(feature = "alloc")]
+pub mod zones;
+
+pub const DAYS_PER_MONTH: u8 = 24;
+pub const MONTHS_PER_YEAR: u8 = 10;
+pub const DAYS_PER_YEAR: u16 = DAYS_PER_MONTH as u16 * MONTHS_PER_YEAR as u16;
+pub const SECONDS_PER_SKS: u128 = 6000;
+pub const SKS_PER_DAY: u128 = 100;
+pub const SECONDS_PER_DAY: u128 = SECONDS_PER_SKS * SKS_PER_DAY;
+// weeks are a fixed run of days that never crosses a month, so every month has the same four weeks
+pub const DAYS_PER_WEEK: u8 = 6;
+pub const WEEKS_PER_MONTH: u8 = DAYS_PER_MONTH / DAYS_PER_WEEK;
+pub const WEEKS_PER_YEAR: u8 = WEEKS_PER_MONTH * MONTHS_PER_YEAR;
+
+// the order months happen in within a year, all greater months come before the lesser ones
+pub const MONTH_ORDER: [(MonthStatus, Month); MONTHS_PER_YEAR as usize] = [
+    (MonthStatus::Greater, Month::Zero),
+    (MonthStatus::Greater, Month::Niktvirin),
+    (MonthStatus::Greater, Month::Apress),
+    (MonthStatus::Greater, Month::Smosh),
+    (MonthStatus::Greater, Month::Funny),
+    (MonthStatus::Lesser, Month::Zero),
+    (MonthStatus::Lesser, Month::Niktvirin),
+    (MonthStatus::Lesser, Month::Apress),
+    (MonthStatus::Lesser, Month::Smosh),
+    (MonthStatus::Lesser, Month::Funny),
+];
+
+// every month has DAYS_PER_MONTH days, there are no short or intercalary months. still go
+// through this when checking a day so there's one place to change if the calendar grows some
+pub const fn days_in_month(_year: u128, _status: MonthStatus, _month: Month) -> u8 {
+    DAYS_PER_MONTH
+}
+
+// zero-based position of the month within the year
+pub const fn month_index(month: (MonthStatus, Month)) -> u8 {
+    order::status_number(month.0) * <Month as MonthExt>::ORDER.len() as u8 + order::month_number(month.1)
+}
+
+// MONTH_ORDER has to agree with the status and month numbering
+const _: () = {
+    assert!(MONTH_ORDER.len() == <Month as MonthExt>::ORDER.len() * <MonthStatus as MonthStatusExt>::ORDER.len());
+    let mut i = 0;
+    while i < MONTH_ORDER.len() {
+        assert!(month_index(MONTH_ORDER[i]) as usize == i);
+        i += 1;
+    }
+};
+
+pub const fn month_from_index(index: u8) -> Option<(MonthStatus, Month)> {
+    if (index as usize) < MONTH_ORDER.len() {
+        Some(MONTH_ORDER[index as usize])
+    } else {
+        None
+    }
+}
+
+// calendar units from coarsest to finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    Year,
+    Month,
+    Day,
+    Sks,
+    Remainder,
+}
+
+impl Granularity {
+    // every unit is a fixed number of ticks, months and years included
+    pub fn ticks(&self) -> u128 {
+        match self {
+            Granularity::Year => DAYS_PER_YEAR as u128 * SECONDS_PER_DAY,
+            Granularity::Month => DAYS_PER_MONTH as u128 * SECONDS_PER_DAY,
+            Granularity::Day => SECONDS_PER_DAY,
+            Granularity::Sks => SECONDS_PER_SKS,
+            Granularity::Remainder => 1,
+        }
+    }
+}
+
+// Debug shows the canonical string, the derived field-by-field form is behind "derive_debug"
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
 pub struct HTDate {
     pub year: u128,
     pub month: (MonthStatus, Month),
@@ -10,142 +158,1627 @@ pub struct HTDate {
     pub second: u128,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum HTParseError {
-    MalformedString,
-    TooManyDays,
-    OtherwiseInvalidDate,
-}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HTParseError {
+    MalformedString,
+    TooManyDays,
+    OtherwiseInvalidDate,
+    InvalidFormatDescription,
+    AmbiguousMatch,
+    UnrecognizedLayout,
+}
+
+#[derive(Clone, Copy)]
+enum Rounding {
+    Down,
+    Up,
+    Nearest,
+}
+
+// the repeating cycle of DAYS_PER_WEEK days. months and years are whole numbers of cycles, so
+// every month starts on a Firstday
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CycleDay {
+    Firstday,
+    Secondday,
+    Thirdday,
+    Fourthday,
+    Fifthday,
+    Restday,
+}
+
+impl CycleDay {
+    pub const ALL: [CycleDay; 6] = [
+        CycleDay::Firstday,
+        CycleDay::Secondday,
+        CycleDay::Thirdday,
+        CycleDay::Fourthday,
+        CycleDay::Fifthday,
+        CycleDay::Restday,
+    ];
+
+    // 1-based, the same numbering as day_of_week
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        CycleDay::ALL.get((number as usize).checked_sub(1)?).copied()
+    }
+}
+
+// what month and year arithmetic does with a day that isn't in the target month. every month has
+// 24 days, so that's only ever a day that was already out of range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPolicy {
+    // move it to the nearest valid day, 1 or 24
+    Clamp,
+    // give None
+    Reject,
+}
+
+// from format_into, with the length the canonical string needed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    pub needed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HTParseOptions {
+    // only treat exactly 8 characters as YYYYGMDD instead of anything that isn't longer
+    pub exact_compact_length: bool,
+    // reject strings whose dashes and T/S/R markers aren't exactly where the layout puts them
+    pub exact_separators: bool,
+    // accept lowercase letters (2019-ga-01t31s2000r)
+    pub allow_lowercase: bool,
+    // accept a space instead of the T between date and time (2019-GA-01 31S2000R)
+    pub allow_space_separator: bool,
+    // accept ordinal dates written out as "2019 day 57"
+    pub allow_ordinal_words: bool,
+    // accept duration units in any order, with spaces and without the P/T markers (T5S 3D)
+    pub allow_any_unit_order: bool,
+}
+
+impl HTParseOptions {
+    pub const LENIENT: HTParseOptions = HTParseOptions {
+        exact_compact_length: false,
+        exact_separators: false,
+        allow_lowercase: true,
+        allow_space_separator: true,
+        allow_ordinal_words: true,
+        allow_any_unit_order: true,
+    };
+    pub const STRICT: HTParseOptions = HTParseOptions {
+        exact_compact_length: true,
+        exact_separators: true,
+        allow_lowercase: false,
+        allow_space_separator: false,
+        allow_ordinal_words: false,
+        allow_any_unit_order: false,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeparatorStyle {
+    Dashed,  // 2019-GA-01T31S2000R
+    Compact, // 2019GA01T31S2000R
+    UrlSafe, // 2019GA01_31S2000R
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LetterCase {
+    Upper,
+    Lower,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HTFormatOptions {
+    // minimum digits, years that need more are never cut short
+    pub year_width: usize,
+    pub include_time: bool,
+    // leading digits of the remainder to keep (truncated, not rounded), 0 leaves it out entirely
+    pub remainder_digits: usize,
+    pub separators: SeparatorStyle,
+    pub case: LetterCase,
+}
+
+impl HTFormatOptions {
+    pub const CANONICAL: HTFormatOptions = HTFormatOptions {
+        year_width: 4,
+        include_time: true,
+        remainder_digits: 4,
+        separators: SeparatorStyle::Dashed,
+        case: LetterCase::Upper,
+    };
+    pub const CANONICAL_NO_SECS: HTFormatOptions = HTFormatOptions {
+        include_time: false,
+        ..HTFormatOptions::CANONICAL
+    };
+    pub const COMPACT: HTFormatOptions = HTFormatOptions {
+        separators: SeparatorStyle::Compact,
+        ..HTFormatOptions::CANONICAL
+    };
+    pub const COMPACT_NO_SECS: HTFormatOptions = HTFormatOptions {
+        include_time: false,
+        ..HTFormatOptions::COMPACT
+    };
+    pub const URL_SAFE: HTFormatOptions = HTFormatOptions {
+        separators: SeparatorStyle::UrlSafe,
+        ..HTFormatOptions::CANONICAL
+    };
+}
+
+impl Default for HTFormatOptions {
+    fn default() -> Self {
+        HTFormatOptions::CANONICAL
+    }
+}
+
+// how many successfully parsed strings needed each leniency
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HTLeniencyCounts {
+    pub lowercase: u64,
+    pub space_separator: u64,
+}
+
+impl HTLeniencyCounts {
+    pub fn total(&self) -> u64 {
+        self.lowercase + self.space_separator
+    }
+
+    fn add(&mut self, other: &HTLeniencyCounts) {
+        self.lowercase += other.lowercase;
+        self.space_separator += other.space_separator;
+    }
+}
+
+impl Display for HTLeniencyCounts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lowercase: {}, space_separator: {}", self.lowercase, self.space_separator)
+    }
+}
+
+// parses many strings with the same options while keeping a tally of the leniencies they needed
+#[derive(Debug, Clone, Default)]
+pub struct HTParseSession {
+    options: HTParseOptions,
+    counts: HTLeniencyCounts,
+    parsed: u64,
+}
+
+impl HTParseSession {
+    pub fn new(options: HTParseOptions) -> Self {
+        HTParseSession {
+            options,
+            counts: HTLeniencyCounts::default(),
+            parsed: 0,
+        }
+    }
+
+    pub fn interpret_string(&mut self, input: &str) -> Result<HTDate, HTParseError> {
+        let date = HTDate::interpret_counted(input, &self.options, &mut self.counts, &StandardCalendar)?;
+        self.parsed += 1;
+        Ok(date)
+    }
+
+    pub fn counts(&self) -> HTLeniencyCounts {
+        self.counts
+    }
+
+    // number of strings parsed successfully, lenient or not
+    pub fn parsed(&self) -> u64 {
+        self.parsed
+    }
+
+    pub fn reset(&mut self) {
+        self.counts = HTLeniencyCounts::default();
+        self.parsed = 0;
+    }
+}
+
+impl Default for HTParseOptions {
+    fn default() -> Self {
+        HTParseOptions::LENIENT
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HTFormat {
+    Compact,         // YYYYGMDD
+    Dashed,          // YYYY-GM-DD
+    CompactWithTime, // YYYYGMDDTNNSNNNNR
+    DashedWithTime,  // YYYY-GM-DDTNNSNNNNR
+    WeekDate,        // YYYY-WNN-D
+    Ordinal,         // YYYY-DDD
+    UrlSafe,         // YYYYGMDD_NNSNNNNR
+}
+
+impl HTFormat {
+    pub const ALL: [HTFormat; 7] = [
+        HTFormat::Compact,
+        HTFormat::Dashed,
+        HTFormat::CompactWithTime,
+        HTFormat::DashedWithTime,
+        HTFormat::WeekDate,
+        HTFormat::Ordinal,
+        HTFormat::UrlSafe,
+    ];
+
+    // Y, D and N are digits, G is the month status letter, M is the month letter,
+    // everything else has to appear literally
+    pub fn layout(&self) -> &'static str {
+        match self {
+            HTFormat::Compact => "YYYYGMDD",
+            HTFormat::Dashed => "YYYY-GM-DD",
+            HTFormat::CompactWithTime => "YYYYGMDDTNNSNNNNR",
+            HTFormat::DashedWithTime => "YYYY-GM-DDTNNSNNNNR",
+            HTFormat::WeekDate => "YYYY-WNN-D",
+            HTFormat::Ordinal => "YYYY-DDD",
+            HTFormat::UrlSafe => "YYYYGMDD_NNSNNNNR",
+        }
+    }
+
+    pub fn has_time(&self) -> bool {
+        matches!(self, HTFormat::CompactWithTime | HTFormat::DashedWithTime | HTFormat::UrlSafe)
+    }
+
+    fn matches(&self, input: &str) -> bool {
+        let layout = self.layout();
+        input.len() == layout.len() && input.bytes().zip(layout.bytes()).all(|(c, l)| match l {
+            b'Y' | b'D' | b'N' => c.is_ascii_digit(),
+            b'G' => status_from_letter(c as char).is_some(),
+            b'M' => month_from_letter(c as char).is_some(),
+            _ => c == l,
+        })
+    }
+}
+
+pub const fn status_letter(status: MonthStatus) -> char {
+    match status {
+        MonthStatus::Greater => 'G',
+        MonthStatus::Lesser => 'L',
+    }
+}
+
+pub const fn month_letter(month: Month) -> char {
+    match month {
+        Month::Zero => 'Z',
+        Month::Niktvirin => 'N',
+        Month::Apress => 'A',
+        Month::Smosh => 'S',
+        Month::Funny => 'F',
+    }
+}
+
+pub const fn status_from_letter(letter: char) -> Option<MonthStatus> {
+    match letter {
+        'G' => Some(MonthStatus::Greater),
+        'L' => Some(MonthStatus::Lesser),
+        _ => None,
+    }
+}
+
+pub const fn month_from_letter(letter: char) -> Option<Month> {
+    match letter {
+        'Z' => Some(Month::Zero),
+        'N' => Some(Month::Niktvirin),
+        'A' => Some(Month::Apress),
+        'S' => Some(Month::Smosh),
+        'F' => Some(Month::Funny),
+        _ => None,
+    }
+}
+
+pub const fn status_name(status: MonthStatus) -> &'static str {
+    match status {
+        MonthStatus::Greater => "Greater",
+        MonthStatus::Lesser => "Lesser",
+    }
+}
+
+pub const fn month_name(month: Month) -> &'static str {
+    match month {
+        Month::Zero => "Zero",
+        Month::Niktvirin => "Niktvirin",
+        Month::Apress => "Apress",
+        Month::Smosh => "Smosh",
+        Month::Funny => "Funny",
+    }
+}
+
+// compares two encodings (sortable keys, packed bytes, ...) without stopping at the first
+// difference, so the time taken doesn't reveal where they differ. lengths aren't secret
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
+
+// english suffix for an ordinal number, 1st 2nd 3rd 4th 11th 21st
+#[cfg(feature = "alloc")]
+fn ordinal_suffix(n: u128) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+fn single_char(input: &str) -> Option<char> {
+    let mut chars = input.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// the four digit remainder of a time, which has to stay inside its sks
+fn parse_remainder(rem_str: &str) -> Result<u128, HTParseError> {
+    let rem = rem_str.parse::<u128>().map_err(|_| HTParseError::MalformedString)?;
+    if rem >= SECONDS_PER_SKS {
+        return Err(HTParseError::OtherwiseInvalidDate);
+    }
+    Ok(rem)
+}
+
+pub fn parse_month_from_gl_and_m(gl_str: &str, month_str: &str) -> Result<(MonthStatus, Month), HTParseError> {
+    let status = single_char(gl_str).and_then(status_from_letter).ok_or(HTParseError::MalformedString)?;
+    let month = single_char(month_str).and_then(month_from_letter).ok_or(HTParseError::MalformedString)?;
+    Ok((status, month))
+}
+
+#[cfg(all(feature = "ht_cal", feature = "standalone"))]
+fn to_ht_cal_month(month: (MonthStatus, Month)) -> (ht_cal::datetime::MonthStatus, ht_cal::datetime::Month) {
+    (month.0.into(), month.1.into())
+}
+
+#[cfg(all(feature = "ht_cal", feature = "standalone"))]
+fn from_ht_cal_month(month: (ht_cal::datetime::MonthStatus, ht_cal::datetime::Month)) -> (MonthStatus, Month) {
+    (month.0.into(), month.1.into())
+}
+
+#[cfg(all(feature = "ht_cal", not(feature = "standalone")))]
+fn to_ht_cal_month(month: (MonthStatus, Month)) -> (MonthStatus, Month) {
+    month
+}
+
+#[cfg(all(feature = "ht_cal", not(feature = "standalone")))]
+fn from_ht_cal_month(month: (MonthStatus, Month)) -> (MonthStatus, Month) {
+    month
+}
+
+// wrappers so the month enums can be displayed and parsed as their single letters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusLetter(pub MonthStatus);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthLetter(pub Month);
+
+impl Display for StatusLetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&status_letter(self.0), f)
+    }
+}
+
+impl Display for MonthLetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&month_letter(self.0), f)
+    }
+}
+
+impl core::str::FromStr for StatusLetter {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        single_char(s).and_then(status_from_letter).map(StatusLetter).ok_or(HTParseError::MalformedString)
+    }
+}
+
+impl core::str::FromStr for MonthLetter {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        single_char(s).and_then(month_from_letter).map(MonthLetter).ok_or(HTParseError::MalformedString)
+    }
+}
+
+// fixed-size buffer for formatting without touching the heap, big enough for any HTDate in
+// either display form (39 digit year, 3 digit day, 35 digit sks)
+#[derive(Clone, Copy)]
+struct StackBuf {
+    buf: [u8; 128],
+    len: usize,
+}
+
+impl StackBuf {
+    fn new() -> Self {
+        StackBuf { buf: [0; 128], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        // only ever written to through write_str, so always valid utf-8
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl core::fmt::Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// chronological: year, then month in MONTH_ORDER, then day and second. compares the fields as
+// they are, so it agrees with Eq even for dates whose second count runs past the end of the day
+impl Ord for HTDate {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.year, month_index(self.month), self.day, self.second).cmp(&(other.year, month_index(other.month), other.day, other.second))
+    }
+}
+
+impl PartialOrd for HTDate {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// date_a - date_b, panicking where checked_duration_since gives None
+impl core::ops::Sub for HTDate {
+    type Output = HTDuration;
+
+    fn sub(self, other: HTDate) -> HTDuration {
+        self.checked_duration_since(&other).expect("dates too far apart to subtract")
+    }
+}
+
+// date + duration and date - duration, panicking where checked_add_duration gives None
+impl core::ops::Add<HTDuration> for HTDate {
+    type Output = HTDate;
+
+    fn add(self, duration: HTDuration) -> HTDate {
+        self.checked_add_duration(duration).expect("overflow when adding duration to date")
+    }
+}
+
+impl core::ops::Sub<HTDuration> for HTDate {
+    type Output = HTDate;
+
+    fn sub(self, duration: HTDuration) -> HTDate {
+        duration
+            .checked_neg()
+            .and_then(|negated| self.checked_add_duration(negated))
+            .expect("overflow when subtracting duration from date")
+    }
+}
+
+impl core::ops::AddAssign<HTDuration> for HTDate {
+    fn add_assign(&mut self, duration: HTDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl core::ops::SubAssign<HTDuration> for HTDate {
+    fn sub_assign(&mut self, duration: HTDuration) {
+        *self = *self - duration;
+    }
+}
+
+// interpret_string with the default options
+impl TryFrom<&str> for HTDate {
+    type Error = HTParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        HTDate::interpret_string(input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for HTDate {
+    type Error = HTParseError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        HTDate::interpret_string(&input)
+    }
+}
+
+// (year, month status, month, day, second) through new_checked
+impl TryFrom<(u128, MonthStatus, Month, u8, u128)> for HTDate {
+    type Error = HTParseError;
+
+    fn try_from((year, month_status, month, day, second): (u128, MonthStatus, Month, u8, u128)) -> Result<Self, Self::Error> {
+        HTDate::new_checked(year, month_status, month, day, second)
+    }
+}
+
+#[cfg(feature = "ht_cal")]
+impl From<HDateTime> for HTDate {
+    fn from(hdt: HDateTime) -> Self {
+        HTDate::from_hdatetime(&hdt)
+    }
+}
+
+#[cfg(feature = "ht_cal")]
+impl From<&HDateTime> for HTDate {
+    fn from(hdt: &HDateTime) -> Self {
+        HTDate::from_hdatetime(hdt)
+    }
+}
+
+#[cfg(feature = "ht_cal")]
+impl From<HTDate> for HDateTime {
+    fn from(date: HTDate) -> Self {
+        date.to_hdatetime()
+    }
+}
+
+#[cfg(feature = "ht_cal")]
+impl From<&HTDate> for HDateTime {
+    fn from(date: &HTDate) -> Self {
+        date.to_hdatetime()
+    }
+}
+
+// the canonical string held inline, from to_array_string
+#[derive(Clone, Copy)]
+pub struct HTDateString {
+    buf: StackBuf,
+}
+
+impl HTDateString {
+    pub fn as_str(&self) -> &str {
+        self.buf.as_str()
+    }
+}
+
+impl core::ops::Deref for HTDateString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for HTDateString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for HTDateString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for HTDateString {}
+
+impl PartialEq<&str> for HTDateString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Display for HTDateString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl core::fmt::Debug for HTDateString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(not(feature = "derive_debug"))]
+impl core::fmt::Debug for HTDate {
+    // the canonical string and the raw second count, which the string hides when it's 100 sks or more
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HTDate({}, second: {})", self, self.second)
+    }
+}
+
+const BASE36_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn pad_untruncated(f: &mut Formatter<'_>, s: &str) -> core::fmt::Result {
+    use core::fmt::Write;
+    let fill = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (fill, 0),
+        Some(core::fmt::Alignment::Center) => (fill / 2, fill - fill / 2),
+        _ => (0, fill),
+    };
+    for _ in 0..before {
+        f.write_char(f.fill())?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(f.fill())?;
+    }
+    Ok(())
+}
+
+impl Display for HTDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+        // written out in full first so width and fill flags apply to the whole date, remembering
+        // where each component ends so a precision can cut between components but never inside one
+        // precisions up to 4 are how many remainder digits to keep instead, since no component
+        // ends before the 4 digit year anyway
+        let mut buf = StackBuf::new();
+        let (width, remainder_digits) = match f.precision() {
+            Some(precision) if precision <= 4 => (None, precision),
+            precision => (precision, 4),
+        };
+        let boundaries = if f.alternate() {
+            write!(buf, "{} {} {}", status_name(self.month.0), month_name(self.month.1), self.day)?;
+            let date_end = buf.len;
+            write!(buf, ", year {}", self.year)?;
+            let year_end = buf.len;
+            write!(buf, ", {} sks", self.second / SECONDS_PER_SKS)?;
+            if remainder_digits > 0 {
+                let rem = self.second % SECONDS_PER_SKS / 10u128.pow(4 - remainder_digits as u32);
+                if remainder_digits == 4 {
+                    write!(buf, " {} rem", rem)?;
+                } else {
+                    write!(buf, " {:0>width$} rem", rem, width = remainder_digits)?;
+                }
+            }
+            [date_end, year_end, buf.len, buf.len, buf.len]
+        } else {
+            let options = HTFormatOptions {
+                remainder_digits,
+                ..HTFormatOptions::CANONICAL
+            };
+            self.write_parts(&mut buf, &options)?
+        };
+        let end = match width {
+            Some(width) => boundaries.iter().copied().filter(|b| *b <= width).max().unwrap_or(0),
+            None => buf.len,
+        };
+        if width.is_none() && f.precision().is_some() {
+            // f.pad would apply the precision again as a character limit
+            pad_untruncated(f, &buf.as_str()[..end])
+        } else {
+            f.pad(&buf.as_str()[..end])
+        }
+    }
+}
+
+impl HTDate {
+    // year 0, Greater Zero 1, the first tick
+    pub const EPOCH: HTDate = HTDate {
+        year: 0,
+        month: (MonthStatus::Greater, Month::Zero),
+        day: 1,
+        second: 0,
+    };
+    pub const MIN: HTDate = HTDate::EPOCH;
+    // the last valid date. epoch arithmetic stops earlier, at HTDate::from_epoch(u128::MAX)
+    pub const MAX: HTDate = HTDate {
+        year: u128::MAX,
+        month: (MonthStatus::Lesser, Month::Funny),
+        day: DAYS_PER_MONTH,
+        second: SECONDS_PER_DAY - 1,
+    };
+
+    // pulls the day and second into range without carrying, for a date that at least sorts
+    // where the fields say. normalize through add_seconds(0) to carry instead
+    pub fn clamped(&self) -> Self {
+        HTDate {
+            day: self.day.clamp(1, self.month_length()),
+            second: self.second.min(SECONDS_PER_DAY - 1),
+            ..*self
+        }
+    }
+    // the one place the numeric layouts are written, returns where each component ends
+    fn write_parts(&self, buf: &mut StackBuf, options: &HTFormatOptions) -> Result<[usize; 5], core::fmt::Error> {
+        use core::fmt::Write;
+        let letter = |c: char| match options.case {
+            LetterCase::Upper => c,
+            LetterCase::Lower => c.to_ascii_lowercase(),
+        };
+        let (date_separator, time_separator) = match options.separators {
+            SeparatorStyle::Dashed => ("-", 'T'),
+            SeparatorStyle::Compact => ("", 'T'),
+            SeparatorStyle::UrlSafe => ("", '_'),
+        };
+        let mut boundaries = [0; 5];
+        write!(buf, "{:0>width$}", self.year, width = options.year_width)?;
+        boundaries[0] = buf.len;
+        write!(buf, "{}{}{}", date_separator, letter(status_letter(self.month.0)), letter(month_letter(self.month.1)))?;
+        boundaries[1] = buf.len;
+        write!(buf, "{}{:0>2}", date_separator, self.day)?;
+        boundaries[2] = buf.len;
+        if options.include_time {
+            write!(buf, "{}{:0>2}{}", letter(time_separator), self.second / SECONDS_PER_SKS, letter('S'))?;
+            boundaries[3] = buf.len;
+            let digits = options.remainder_digits.min(4);
+            if digits > 0 {
+                let rem = self.second % SECONDS_PER_SKS / 10u128.pow(4 - digits as u32);
+                write!(buf, "{:0>digits$}{}", rem, letter('R'))?;
+            }
+        } else {
+            boundaries[3] = buf.len;
+        }
+        boundaries[4] = buf.len;
+        Ok(boundaries)
+    }
+
+    pub fn write_with<W: core::fmt::Write>(&self, writer: &mut W, options: &HTFormatOptions) -> core::fmt::Result {
+        let mut buf = StackBuf::new();
+        self.write_parts(&mut buf, options)?;
+        writer.write_str(buf.as_str())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_string_with(&self, options: &HTFormatOptions) -> String {
+        let mut string = String::new();
+        // writing to a String can't fail
+        let _ = self.write_with(&mut string, options);
+        string
+    }
+
+    pub const fn new(year: u128, month_status: MonthStatus, month: Month, day: u8, second: u128) -> HTDate {
+        HTDate {
+            year,
+            month: (month_status, month),
+            day,
+            second,
+        }
+    }
+    // same, but Err for a day the month doesn't have or a second past the end of the day
+    pub const fn new_checked(year: u128, month_status: MonthStatus, month: Month, day: u8, second: u128) -> Result<HTDate, HTParseError> {
+        HTDate::new(year, month_status, month, day, second).validated()
+    }
+    #[cfg(feature = "ht_cal")]
+    pub fn to_hdatetime(&self) -> HDateTime {
+        let mut hdt = HDateTime::new();
+        hdt.year = self.year;
+        hdt.month = to_ht_cal_month(self.month);
+        hdt.day = self.day;
+        hdt.second = core::num::Wrapping(self.second);
+        hdt
+    }
+
+    #[cfg(feature = "ht_cal")]
+    pub fn from_hdatetime(htdatetime: &HDateTime) -> Self {
+        HTDate {
+            year: htdatetime.year,
+            month: from_ht_cal_month(htdatetime.month),
+            day: htdatetime.day,
+            second: htdatetime.second.0,
+        }
+    }
+
+    // appends the canonical string to an existing buffer
+    pub fn write_to<W: core::fmt::Write>(&self, writer: &mut W) -> core::fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_io<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    // like to_string but never allocates
+    pub fn to_array_string(&self) -> HTDateString {
+        let mut buf = StackBuf::new();
+        // the canonical form of any u128 year fits the stack buffer
+        let _ = self.write_parts(&mut buf, &HTFormatOptions::CANONICAL);
+        HTDateString { buf }
+    }
+
+    // canonical string as ascii into the start of buf, without allocating. gives the length written
+    pub fn format_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let string = self.to_array_string();
+        let bytes = string.as_bytes();
+        let out = buf.get_mut(..bytes.len()).ok_or(BufferTooSmall { needed: bytes.len() })?;
+        out.copy_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    // canonical string stored inline, any valid date with a year of nine digits or less fits without allocating
+    #[cfg(feature = "compact_str")]
+    pub fn to_compact_str(&self) -> compact_str::CompactString {
+        compact_str::format_compact!("{}", self)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_string_no_secs(&self) -> String {
+        self.to_string_truncated(Granularity::Day)
+    }
+
+    // canonical string cut after the given unit, e.g. "2019-GA" for Month
+    #[cfg(feature = "alloc")]
+    pub fn to_string_truncated(&self, granularity: Granularity) -> String {
+        let mut buf = StackBuf::new();
+        // the canonical form of any u128 year fits the buffer
+        match self.write_parts(&mut buf, &HTFormatOptions::CANONICAL) {
+            Ok(boundaries) => buf.as_str()[..boundaries[granularity as usize]].to_string(),
+            Err(_) => self.to_string(),
+        }
+    }
+
+    // equality that always looks at every field, for expiry dates in signed tokens
+    pub fn eq_constant_time(&self, other: &HTDate) -> bool {
+        let diff = (self.year ^ other.year)
+            | (self.second ^ other.second)
+            | (self.day ^ other.day) as u128
+            | (month_index(self.month) ^ month_index(other.month)) as u128;
+        core::hint::black_box(diff) == 0
+    }
+
+    // ticks since the start of year 0, None if the year is too large to count that far
+    pub fn checked_epoch(&self) -> Option<u128> {
+        self.checked_day_number()?.checked_mul(SECONDS_PER_DAY)?.checked_add(self.second)
+    }
+
+    // days since the epoch's first day
+    fn checked_day_number(&self) -> Option<u128> {
+        self.year.checked_mul(DAYS_PER_YEAR as u128)?.checked_add(self.ordinal() as u128 - 1)
+    }
+
+    // continuous count of days from the epoch, 0 for its first day. signed so differences can be
+    // taken directly; None for years too far out to count
+    pub fn to_day_number(&self) -> Option<i128> {
+        i128::try_from(self.checked_day_number()?).ok()
+    }
+
+    // start of the day, None for days before the epoch
+    pub fn from_day_number(day_number: i128) -> Option<Self> {
+        Some(HTDate::from_day_number_at(u128::try_from(day_number).ok()?, 0))
+    }
+
+    // day number * SECONDS_PER_DAY + second, the signed counterpart of checked_epoch
+    pub fn to_epoch_seconds(&self) -> Option<i128> {
+        i128::try_from(self.checked_epoch()?).ok()
+    }
+
+    // always normalized, None before the epoch
+    pub fn from_epoch_seconds(seconds: i128) -> Option<Self> {
+        Some(HTDate::from_epoch(u128::try_from(seconds).ok()?))
+    }
+
+    // inverse of checked_epoch, always gives a normalized date
+    pub fn from_epoch(ticks: u128) -> Self {
+        HTDate::from_day_number_at(ticks / SECONDS_PER_DAY, ticks % SECONDS_PER_DAY)
+    }
+
+    pub fn checked_add_duration(&self, duration: HTDuration) -> Option<Self> {
+        let epoch = self.checked_epoch()?;
+        let ticks = if duration.is_negative() {
+            epoch.checked_sub(duration.ticks().unsigned_abs())?
+        } else {
+            epoch.checked_add(duration.ticks() as u128)?
+        };
+        Some(HTDate::from_epoch(ticks))
+    }
+
+    // carries past the end of the day into the next days, months and years. the result is always
+    // normalized, even if self had a second count past the end of its day
+    pub fn checked_add_seconds(&self, seconds: i128) -> Option<Self> {
+        self.checked_add_duration(HTDuration::from_ticks(seconds))
+    }
+
+    pub fn add_seconds(&self, seconds: i128) -> Self {
+        self.checked_add_seconds(seconds).expect("overflow when adding seconds to date")
+    }
+
+    // clamps to the first or last date whose epoch fits a u128
+    pub fn saturating_add_seconds(&self, seconds: i128) -> Self {
+        self.checked_add_seconds(seconds).unwrap_or_else(|| {
+            let epoch = if seconds < 0 && self.checked_epoch().is_some() { 0 } else { u128::MAX };
+            HTDate::from_epoch(epoch)
+        })
+    }
+
+    // epoch ticks wrap around at u128::MAX like integer wrapping
+    pub fn wrapping_add_seconds(&self, seconds: i128) -> Self {
+        let epoch = self
+            .year
+            .wrapping_mul(DAYS_PER_YEAR as u128)
+            .wrapping_add(self.ordinal() as u128 - 1)
+            .wrapping_mul(SECONDS_PER_DAY)
+            .wrapping_add(self.second);
+        HTDate::from_epoch(epoch.wrapping_add_signed(seconds))
+    }
+
+    // day within the month and second within the day, the other fields can't be out of range
+    pub fn is_valid(&self) -> bool {
+        (1..=self.month_length()).contains(&self.day) && self.second < SECONDS_PER_DAY
+    }
+
+    // another name for is_valid, the dates normalize gives
+    pub fn is_normalized(&self) -> bool {
+        self.is_valid()
+    }
+
+    // carries a second past the end of the day into days and a day past the end of the month into
+    // months and years, so 2019GA30 becomes 2019GS06. day 0 is the last day of the month before.
+    // None if that goes before the epoch or past the last year
+    pub fn normalize(&self) -> Option<Self> {
+        let month_start = self.year.checked_mul(DAYS_PER_YEAR as u128)?.checked_add(month_index(self.month) as u128 * DAYS_PER_MONTH as u128)?;
+        let day_number = month_start.checked_add(self.day as u128)?.checked_sub(1)?.checked_add(self.second / SECONDS_PER_DAY)?;
+        Some(HTDate::from_day_number_at(day_number, self.second % SECONDS_PER_DAY))
+    }
+
+    const fn month_length(&self) -> u8 {
+        days_in_month(self.year, self.month.0, self.month.1)
+    }
+
+    // copies with one field replaced, Err if the result isn't a valid date (including a self
+    // that wasn't valid to begin with)
+    pub fn with_year(&self, year: u128) -> Result<Self, HTParseError> {
+        HTDate { year, ..*self }.validated()
+    }
+
+    pub fn with_month(&self, month_status: MonthStatus, month: Month) -> Result<Self, HTParseError> {
+        HTDate {
+            month: (month_status, month),
+            ..*self
+        }
+        .validated()
+    }
+
+    pub fn with_day(&self, day: u8) -> Result<Self, HTParseError> {
+        HTDate { day, ..*self }.validated()
+    }
+
+    pub fn with_second(&self, second: u128) -> Result<Self, HTParseError> {
+        HTDate { second, ..*self }.validated()
+    }
+
+    // like the with_ methods but pulling anything out of range to the nearest valid value instead
+    // of failing, so they can be chained
+    pub fn with_day_clamped(&self, day: u8) -> Self {
+        HTDate { day, ..*self }.clamped()
+    }
+
+    pub fn with_month_clamped(&self, month_status: MonthStatus, month: Month) -> Self {
+        HTDate {
+            month: (month_status, month),
+            ..*self
+        }
+        .clamped()
+    }
+
+    // sks past the end of the day and remainder past the end of the sks are clamped separately
+    pub fn at_time(&self, sks: u128, rem: u128) -> Self {
+        let sks = sks.min(SECONDS_PER_DAY / SECONDS_PER_SKS - 1);
+        HTDate {
+            second: sks * SECONDS_PER_SKS + rem.min(SECONDS_PER_SKS - 1),
+            ..*self
+        }
+        .clamped()
+    }
+
+    // the first day of the following month at the start of the day, HTDate::MAX in the last month
+    pub fn at_start_of_next_month(&self) -> Self {
+        self.add_months(1, DayPolicy::Clamp).map(|date| date.start_of_month()).unwrap_or(HTDate::MAX)
+    }
+
+    // validated_in(&StandardCalendar), kept separate so it can be const
+    const fn validated(self) -> Result<Self, HTParseError> {
+        if self.day > self.month_length() {
+            return Err(HTParseError::TooManyDays);
+        }
+        if self.day == 0 || self.second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(self)
+    }
+
+    // the same checks against another calendar's months and lengths. a month the calendar doesn't
+    // have is OtherwiseInvalidDate
+    pub(crate) fn validated_in<C: HTCalendar + ?Sized>(self, calendar: &C) -> Result<Self, HTParseError> {
+        if calendar.month_position(self.month).is_none() {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        if self.day > calendar.days_in_month(self.year, self.month.0, self.month.1) {
+            return Err(HTParseError::TooManyDays);
+        }
+        if self.day == 0 || self.second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(self)
+    }
+
+    // rolls over month ends and from Greater into Lesser, keeping the time of day.
+    // None past either end of the year range
+    pub fn checked_add_days(&self, days: u128) -> Option<Self> {
+        Some(HTDate::from_day_number_at(self.checked_day_number()?.checked_add(days)?, self.second))
+    }
+
+    pub fn checked_sub_days(&self, days: u128) -> Option<Self> {
+        Some(HTDate::from_day_number_at(self.checked_day_number()?.checked_sub(days)?, self.second))
+    }
+
+    // one day forward or back keeping the time of day, None at the first or last day
+    pub fn succ_day(&self) -> Option<Self> {
+        self.checked_add_days(1)
+    }
+
+    pub fn pred_day(&self) -> Option<Self> {
+        self.checked_sub_days(1)
+    }
+
+    // clamps to the first or last day, keeping the time of day
+    pub fn saturating_add_days(&self, days: u128) -> Self {
+        let day_number = self.checked_day_number().unwrap_or(u128::MAX);
+        HTDate::from_day_number_at(day_number.saturating_add(days), self.second)
+    }
+
+    pub fn saturating_sub_days(&self, days: u128) -> Self {
+        let day_number = self.checked_day_number().unwrap_or(u128::MAX);
+        HTDate::from_day_number_at(day_number.saturating_sub(days), self.second)
+    }
+
+    // the day count wraps around at u128::MAX like integer wrapping
+    pub fn wrapping_add_days(&self, days: u128) -> Self {
+        let day_number = self.year.wrapping_mul(DAYS_PER_YEAR as u128).wrapping_add(self.ordinal() as u128 - 1);
+        HTDate::from_day_number_at(day_number.wrapping_add(days), self.second)
+    }
+
+    pub fn wrapping_sub_days(&self, days: u128) -> Self {
+        let day_number = self.year.wrapping_mul(DAYS_PER_YEAR as u128).wrapping_add(self.ordinal() as u128 - 1);
+        HTDate::from_day_number_at(day_number.wrapping_sub(days), self.second)
+    }
+
+    fn from_day_number_at(day_number: u128, second: u128) -> Self {
+        let day_of_year = (day_number % DAYS_PER_YEAR as u128) as u16;
+        let (month_status, month) = MONTH_ORDER[(day_of_year / DAYS_PER_MONTH as u16) as usize];
+        let day = (day_of_year % DAYS_PER_MONTH as u16) as u8 + 1;
+        HTDate::new(day_number / DAYS_PER_YEAR as u128, month_status, month, day, second)
+    }
+
+    // signed time from earlier to self, None if either is too far out or the gap doesn't fit an i128
+    pub fn checked_duration_since(&self, earlier: &HTDate) -> Option<HTDuration> {
+        let (this, earlier) = (self.checked_epoch()?, earlier.checked_epoch()?);
+        let ticks = if this >= earlier {
+            i128::try_from(this - earlier).ok()?
+        } else {
+            0i128.checked_sub_unsigned(earlier - this)?
+        };
+        Some(HTDuration::from_ticks(ticks))
+    }
+
+    // snapping to unit boundaries, counted from the epoch. None if the date or the result doesn't
+    // have an epoch that fits a u128
+    pub fn floor_to(&self, granularity: Granularity) -> Option<Self> {
+        self.snap(granularity.ticks(), Rounding::Down)
+    }
+
+    pub fn ceil_to(&self, granularity: Granularity) -> Option<Self> {
+        self.snap(granularity.ticks(), Rounding::Up)
+    }
+
+    // halfway rounds up
+    pub fn round_to(&self, granularity: Granularity) -> Option<Self> {
+        self.snap(granularity.ticks(), Rounding::Nearest)
+    }
+
+    // e.g. to the nearest 10 sks, None for a step that isn't positive
+    pub fn floor_to_multiple(&self, step: HTDuration) -> Option<Self> {
+        self.snap(u128::try_from(step.ticks()).ok().filter(|step| *step > 0)?, Rounding::Down)
+    }
+
+    pub fn ceil_to_multiple(&self, step: HTDuration) -> Option<Self> {
+        self.snap(u128::try_from(step.ticks()).ok().filter(|step| *step > 0)?, Rounding::Up)
+    }
+
+    pub fn round_to_multiple(&self, step: HTDuration) -> Option<Self> {
+        self.snap(u128::try_from(step.ticks()).ok().filter(|step| *step > 0)?, Rounding::Nearest)
+    }
+
+    fn snap(&self, step: u128, rounding: Rounding) -> Option<Self> {
+        let epoch = self.checked_epoch()?;
+        let down = epoch - epoch % step;
+        let up = || down.checked_add(if epoch % step == 0 { 0 } else { step });
+        let snapped = match rounding {
+            Rounding::Down => down,
+            Rounding::Up => up()?,
+            Rounding::Nearest if epoch % step < step - step / 2 => down,
+            Rounding::Nearest => up()?,
+        };
+        Some(HTDate::from_epoch(snapped))
+    }
+
+    // first and last ticks of the day, month and year the date is in
+    pub fn start_of_day(&self) -> Self {
+        HTDate { second: 0, ..*self }
+    }
+
+    pub fn end_of_day(&self) -> Self {
+        HTDate {
+            second: SECONDS_PER_DAY - 1,
+            ..*self
+        }
+    }
+
+    pub fn start_of_month(&self) -> Self {
+        HTDate { day: 1, ..self.start_of_day() }
+    }
+
+    pub fn end_of_month(&self) -> Self {
+        HTDate {
+            day: self.month_length(),
+            ..self.end_of_day()
+        }
+    }
+
+    pub fn start_of_year(&self) -> Self {
+        HTDate {
+            month: MONTH_ORDER[0],
+            ..self.start_of_month()
+        }
+    }
+
+    pub fn end_of_year(&self) -> Self {
+        HTDate {
+            month: MONTH_ORDER[MONTH_ORDER.len() - 1],
+            ..self.end_of_month()
+        }
+    }
+
+    // steps through the months in calendar order, Lesser Funny to the next year's Greater Zero.
+    // None before year 0 or past the last year
+    pub fn add_months(&self, months: i64, policy: DayPolicy) -> Option<Self> {
+        let month_number = self
+            .year
+            .checked_mul(MONTHS_PER_YEAR as u128)?
+            .checked_add(month_index(self.month) as u128)?
+            .checked_add_signed(months as i128)?;
+        let year = month_number / MONTHS_PER_YEAR as u128;
+        let (month_status, month) = month_from_index((month_number % MONTHS_PER_YEAR as u128) as u8)?;
+        let day = self.day_under(policy, days_in_month(year, month_status, month))?;
+        Some(HTDate::new(year, month_status, month, day, self.second))
+    }
+
+    pub fn sub_months(&self, months: i64, policy: DayPolicy) -> Option<Self> {
+        self.add_months(months.checked_neg()?, policy)
+    }
+
+    // self's day in a month of the given length
+    fn day_under(&self, policy: DayPolicy, length: u8) -> Option<u8> {
+        match policy {
+            DayPolicy::Clamp => Some(self.day.clamp(1, length)),
+            DayPolicy::Reject => (1..=length).contains(&self.day).then_some(self.day),
+        }
+    }
+
+    // negative years go back, None before year 0, past the last year, or for a rejected day
+    pub fn checked_add_years(&self, years: i128, policy: DayPolicy) -> Option<Self> {
+        let year = self.year.checked_add_signed(years)?;
+        Some(HTDate {
+            year,
+            day: self.day_under(policy, days_in_month(year, self.month.0, self.month.1))?,
+            ..*self
+        })
+    }
+
+    pub fn add_years(&self, years: i128, policy: DayPolicy) -> Self {
+        self.checked_add_years(years, policy).expect("year out of range or day rejected")
+    }
+
+    // stops at year 0 or the last year, and always clamps the day since there's nothing to return instead
+    pub fn saturating_add_years(&self, years: i128) -> Self {
+        let year = if years < 0 {
+            self.year.saturating_sub(years.unsigned_abs())
+        } else {
+            self.year.saturating_add(years as u128)
+        };
+        HTDate {
+            year,
+            day: self.day.clamp(1, days_in_month(year, self.month.0, self.month.1)),
+            ..*self
+        }
+    }
+
+    // checked_add_years with DayPolicy::Reject, for unsigned counts. None also for counts past
+    // i128::MAX
+    pub fn add_years_checked(&self, years: u128) -> Option<Self> {
+        self.checked_add_years(i128::try_from(years).ok()?, DayPolicy::Reject)
+    }
+
+    pub fn sub_years_checked(&self, years: u128) -> Option<Self> {
+        self.checked_add_years(-i128::try_from(years).ok()?, DayPolicy::Reject)
+    }
+
+    // 1-based day of the year
+    pub fn ordinal(&self) -> u16 {
+        month_index(self.month) as u16 * DAYS_PER_MONTH as u16 + self.day.max(1) as u16
+    }
+
+    pub fn from_ordinal(year: u128, ordinal: u16) -> Result<Self, HTParseError> {
+        if ordinal == 0 || ordinal > DAYS_PER_YEAR {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        let (month_status, month) = month_from_index(((ordinal - 1) / DAYS_PER_MONTH as u16) as u8).ok_or(HTParseError::OtherwiseInvalidDate)?;
+        let day = ((ordinal - 1) % DAYS_PER_MONTH as u16) as u8 + 1;
+        Ok(HTDate::new(year, month_status, month, day, 0))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_ordinal_string(&self) -> String {
+        format!("{:0>4}-{:0>3}", self.year, self.ordinal())
+    }
+
+    pub fn interpret_ordinal_string(input: &str) -> Result<Self, HTParseError> {
+        HTDate::interpret_ordinal_string_with(input, &HTParseOptions::default())
+    }
+
+    pub fn interpret_ordinal_string_with(input: &str, options: &HTParseOptions) -> Result<Self, HTParseError> {
+        let (year, ordinal) = HTDate::read_ordinal_string(input, options)?;
+        HTDate::from_ordinal(year, ordinal)
+    }
+
+    // ordinals counted through a homebrew calendar's months, at least three digits since its
+    // years can be longer
+    #[cfg(feature = "alloc")]
+    pub fn to_ordinal_string_in<C: HTCalendar + ?Sized>(&self, calendar: &C) -> Option<String> {
+        Some(format!("{:0>4}-{:0>3}", self.year, calendar.ordinal(self)?))
+    }
+
+    pub fn interpret_ordinal_string_in<C: HTCalendar + ?Sized>(input: &str, calendar: &C) -> Result<Self, HTParseError> {
+        let (year, ordinal) = HTDate::read_ordinal_string(input, &HTParseOptions::default())?;
+        calendar.date_at_ordinal(year, ordinal as u32).ok_or(HTParseError::OtherwiseInvalidDate)
+    }
+
+    // the year and day of the year, without checking the day against a calendar
+    fn read_ordinal_string(input: &str, options: &HTParseOptions) -> Result<(u128, u16), HTParseError> {
+        // YYYY-DDD, or YYYY day N when lenient
+        if HTDate::detect_format(input) != Some(HTFormat::Ordinal) {
+            let mut words = input.split_whitespace();
+            return match (words.next(), words.next(), words.next(), words.next()) {
+                (Some(year), Some(day), Some(ordinal), None) if options.allow_ordinal_words && day.eq_ignore_ascii_case("day") => {
+                    if !year.bytes().all(|b| b.is_ascii_digit()) || !ordinal.bytes().all(|b| b.is_ascii_digit()) {
+                        return Err(HTParseError::MalformedString);
+                    }
+                    let year = year.parse().map_err(|_| HTParseError::MalformedString)?;
+                    let ordinal = ordinal.parse().map_err(|_| HTParseError::OtherwiseInvalidDate)?;
+                    Ok((year, ordinal))
+                }
+                _ => Err(HTParseError::MalformedString),
+            };
+        }
+        let year = input[0..4].parse().map_err(|_| HTParseError::MalformedString)?;
+        let ordinal = input[5..8].parse().map_err(|_| HTParseError::MalformedString)?;
+        Ok((year, ordinal))
+    }
+
+    // 1-based week of the year
+    pub fn week_of_year(&self) -> u8 {
+        ((self.ordinal() - 1) / DAYS_PER_WEEK as u16) as u8 + 1
+    }
+
+    // 1-based day within the week
+    pub fn day_of_week(&self) -> u8 {
+        self.day.saturating_sub(1) % DAYS_PER_WEEK + 1
+    }
+
+    pub fn day_of_cycle(&self) -> CycleDay {
+        CycleDay::ALL[self.day_of_week() as usize - 1]
+    }
+
+    // the first later day that is a cycle_day, at the same time of day. a week on for the same day
+    pub fn next_occurrence_of(&self, cycle_day: CycleDay) -> Option<Self> {
+        let ahead = (cycle_day.number() + DAYS_PER_WEEK - self.day_of_week() - 1) % DAYS_PER_WEEK + 1;
+        self.checked_add_days(ahead as u128)
+    }
+
+    pub fn from_week_date(year: u128, week: u8, day_of_week: u8) -> Result<Self, HTParseError> {
+        if week == 0 || week > WEEKS_PER_YEAR || day_of_week == 0 || day_of_week > DAYS_PER_WEEK {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        let (month_status, month) = month_from_index((week - 1) / WEEKS_PER_MONTH).ok_or(HTParseError::OtherwiseInvalidDate)?;
+        let day = (week - 1) % WEEKS_PER_MONTH * DAYS_PER_WEEK + day_of_week;
+        Ok(HTDate::new(year, month_status, month, day, 0))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_week_date_string(&self) -> String {
+        format!("{:0>4}-W{:0>2}-{}", self.year, self.week_of_year(), self.day_of_week())
+    }
+
+    pub fn interpret_week_string(input: &str) -> Result<Self, HTParseError> {
+        // YYYY-WNN-D
+        if HTDate::detect_format(input) != Some(HTFormat::WeekDate) {
+            return Err(HTParseError::MalformedString);
+        }
+        let year = input[0..4].parse().map_err(|_| HTParseError::MalformedString)?;
+        let week = input[6..8].parse().map_err(|_| HTParseError::MalformedString)?;
+        let day_of_week = input[9..10].parse().map_err(|_| HTParseError::MalformedString)?;
+        HTDate::from_week_date(year, week, day_of_week)
+    }
+
+    // only looks at the shape of the string, so a detected format can still fail to parse
+    // (e.g. day 25)
+    pub fn detect_format(input: &str) -> Option<HTFormat> {
+        HTFormat::ALL.iter().copied().find(|format| format.matches(input))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_compact_string(&self) -> String {
+        self.to_string_with(&HTFormatOptions::COMPACT)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_compact_string_no_secs(&self) -> String {
+        self.to_string_with(&HTFormatOptions::COMPACT_NO_SECS)
+    }
+
+    // fixed-width digits only: 39 digit year, month index, 2 digit day, 6 digit second of the day.
+    // for any two dates this accepts, a < b as strings exactly when a is earlier than b, which is
+    // why days past 24 and seconds past the end of the day are refused instead of widening a field
+    #[cfg(feature = "alloc")]
+    pub fn to_sortable_key(&self) -> Result<String, HTParseError> {
+        if self.day > self.month_length() || self.second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(format!("{:0>39}{}{:0>2}{:0>6}", self.year, month_index(self.month), self.day, self.second))
+    }
+
+    pub fn from_sortable_key(key: &str) -> Result<Self, HTParseError> {
+        if key.len() != 48 || !key.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(HTParseError::MalformedString);
+        }
+        let year = key[0..39].parse().map_err(|_| HTParseError::MalformedString)?;
+        let (month_status, month) = month_from_index(key[39..40].parse().map_err(|_| HTParseError::MalformedString)?).ok_or(HTParseError::MalformedString)?;
+        let day = key[40..42].parse().map_err(|_| HTParseError::MalformedString)?;
+        let second = key[42..48].parse().map_err(|_| HTParseError::MalformedString)?;
+        if day > days_in_month(year, month_status, month) {
+            return Err(HTParseError::TooManyDays);
+        }
+        if second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(HTDate::new(year, month_status, month, day, second))
+    }
+
+    // 16 bytes, little-endian: year as u64 (0..8), month index (8), day (9), second as u32 (10..14),
+    // then 2 reserved zero bytes. years past u64::MAX don't fit
+    pub fn encode_packed(&self, out: &mut [u8; 16]) -> Result<(), HTParseError> {
+        if self.day > self.month_length() || self.second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        let year = u64::try_from(self.year).map_err(|_| HTParseError::OtherwiseInvalidDate)?;
+        out[0..8].copy_from_slice(&year.to_le_bytes());
+        out[8] = month_index(self.month);
+        out[9] = self.day;
+        out[10..14].copy_from_slice(&(self.second as u32).to_le_bytes());
+        out[14..16].fill(0);
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<[u8; 16], HTParseError> {
+        let mut bytes = [0; 16];
+        self.encode_packed(&mut bytes)?;
+        Ok(bytes)
+    }
 
-pub fn parse_month_from_gl_and_m(gl_str: &str, month_str: &str) -> Result<(MonthStatus, Month), HTParseError> {
-    Ok(match gl_str {
-        "G" => (MonthStatus::Greater, match month_str {
-            "Z" => Month::Zero,
-            "N" => Month::Niktvirin,
-            "A" => Month::Apress,
-            "S" => Month::Smosh,
-            "F" => Month::Funny,
-            _ => return Err(HTParseError::MalformedString),
-        }),
-        "L" => (MonthStatus::Lesser, match month_str {
-            "Z" => Month::Zero,
-            "N" => Month::Niktvirin,
-            "A" => Month::Apress,
-            "S" => Month::Smosh,
-            "F" => Month::Funny,
-            _ => return Err(HTParseError::MalformedString),
-        }),
-        _ => return Err(HTParseError::MalformedString),
-    })
-}
+    pub fn decode_packed(bytes: &[u8; 16]) -> Result<Self, HTParseError> {
+        if bytes[14..16] != [0, 0] {
+            return Err(HTParseError::MalformedString);
+        }
+        let mut year = [0; 8];
+        year.copy_from_slice(&bytes[0..8]);
+        let (month_status, month) = month_from_index(bytes[8]).ok_or(HTParseError::MalformedString)?;
+        let day = bytes[9];
+        let mut second = [0; 4];
+        second.copy_from_slice(&bytes[10..14]);
+        let second = u32::from_le_bytes(second) as u128;
+        let year = u64::from_le_bytes(year) as u128;
+        if day > days_in_month(year, month_status, month) {
+            return Err(HTParseError::TooManyDays);
+        }
+        if second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(HTDate::new(year, month_status, month, day, second))
+    }
 
-impl Display for HTDate {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let gl = if self.month.0 == MonthStatus::Greater { "G" } else { "L" };
-        let month = match self.month.1 {
-            Month::Zero => "Z",
-            Month::Niktvirin => "N",
-            Month::Apress => "A",
-            Month::Smosh => "S",
-            Month::Funny => "F",
-        };
-        let year_padded = format!("{:0>4}", self.year); // todo: add more digits when year is greater than 9999
-        let day_padded = format!("{:0>2}", self.day);
-        let sks = self.second / 6000;
-        let rem = self.second % 6000;
-        let sks_padded = format!("{:0>2}", sks);
-        let rem_padded = format!("{:0>4}", rem);
-        format!("{}-{}{}-{}T{}S{}R", year_padded, gl, month, day_padded, sks_padded, rem_padded).fmt(f)
+    // epoch ticks in lowercase base36, after one more base36 digit giving their count so that
+    // longer ids still sort later, e.g. "8" + 8 digits for dates around year 2000
+    #[cfg(feature = "alloc")]
+    pub fn to_base36(&self) -> Result<String, HTParseError> {
+        let mut ticks = self.checked_epoch().ok_or(HTParseError::OtherwiseInvalidDate)?;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(BASE36_DIGITS[(ticks % 36) as usize]);
+            ticks /= 36;
+            if ticks == 0 {
+                break;
+            }
+        }
+        digits.push(BASE36_DIGITS[digits.len()]);
+        digits.reverse();
+        // only ascii digits and letters went in
+        Ok(String::from_utf8(digits).unwrap_or_default())
     }
-}
 
-impl HTDate {
-    pub fn new(year: u128, month_status: MonthStatus, month: Month, day: u8, second: u128) -> HTDate {
-        HTDate {
-            year,
-            month: (month_status, month),
-            day,
-            second,
+    pub fn from_base36(id: &str) -> Result<Self, HTParseError> {
+        let digit = |c: u8| BASE36_DIGITS.iter().position(|d| *d == c).ok_or(HTParseError::MalformedString);
+        let (count, digits) = id.as_bytes().split_first().ok_or(HTParseError::MalformedString)?;
+        // exactly the count given, without leading zeros so every date has one id
+        if digit(*count)? != digits.len() || (digits.len() > 1 && digits[0] == b'0') {
+            return Err(HTParseError::MalformedString);
+        }
+        let mut ticks = 0u128;
+        for c in digits {
+            ticks = ticks
+                .checked_mul(36)
+                .and_then(|ticks| ticks.checked_add(digit(*c).ok()? as u128))
+                .ok_or(HTParseError::MalformedString)?;
         }
+        Ok(HTDate::from_epoch(ticks))
     }
-    pub fn to_hdatetime(&self) -> HDateTime {
-        let mut hdt = HDateTime::new();
-        hdt.year = self.year;
-        hdt.month = (self.month.0, self.month.1);
-        hdt.day = self.day;
-        hdt.second = Wrapping(self.second);
-        hdt
+
+    // re-emits the date in a layout, e.g. the one detect_format found on the way in
+    #[cfg(feature = "alloc")]
+    pub fn to_string_as(&self, format: HTFormat) -> String {
+        match format {
+            HTFormat::Compact => self.to_compact_string_no_secs(),
+            HTFormat::Dashed => self.to_string_no_secs(),
+            HTFormat::CompactWithTime => self.to_compact_string(),
+            HTFormat::DashedWithTime => self.to_string(),
+            HTFormat::WeekDate => self.to_week_date_string(),
+            HTFormat::Ordinal => self.to_ordinal_string(),
+            HTFormat::UrlSafe => self.to_url_safe_string(),
+        }
     }
 
-    pub fn from_hdatetime(htdatetime: &HDateTime) -> Self {
-        HTDate {
-            year: htdatetime.year,
-            month: htdatetime.month,
-            day: htdatetime.day,
-            second: htdatetime.second.0,
+    // for in-universe documents, e.g. "the 1st day of Greater Apress, year 2019, at 31 sks and 2000 remainder"
+    #[cfg(feature = "alloc")]
+    pub fn to_prose_string(&self) -> String {
+        self.format_localized(&HTLocale::ENGLISH)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn format_localized(&self, locale: &HTLocale) -> String {
+        locale.format(self)
+    }
+
+    // "1st", "2nd", "23rd"
+    #[cfg(feature = "alloc")]
+    pub fn day_ordinal_string(&self) -> String {
+        self.day_ordinal_string_localized(&HTLocale::ENGLISH)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn day_ordinal_string_localized(&self, locale: &HTLocale) -> String {
+        locale.ordinal(self.day as u128)
+    }
+
+    // only letters, digits and an underscore, so it can go in filenames and urls as is
+    #[cfg(feature = "alloc")]
+    pub fn to_url_safe_string(&self) -> String {
+        self.to_string_with(&HTFormatOptions::URL_SAFE)
+    }
+
+    pub fn interpret_url_safe_string(input: &str) -> Result<Self, HTParseError> {
+        if HTDate::detect_format(input) != Some(HTFormat::UrlSafe) {
+            return Err(HTParseError::MalformedString);
         }
+        HTDate::interpret_string_with(input, &HTParseOptions::STRICT)
     }
 
-    pub fn to_string_no_secs(&self) -> String {
-        let gl = if self.month.0 == MonthStatus::Greater { "G" } else { "L" };
-        let month = match self.month.1 {
-            Month::Zero => "Z",
-            Month::Niktvirin => "N",
-            Month::Apress => "A",
-            Month::Smosh => "S",
-            Month::Funny => "F",
-        };
-        let year_padded = format!("{:0>4}", self.year); // todo: add more digits when year is greater than 9999
-        let day_padded = format!("{:0>2}", self.day);
-        format!("{}-{}{}-{}", year_padded, gl, month, day_padded)
+    #[cfg(feature = "alloc")]
+    pub fn format_with(&self, format: &FormatDescription) -> String {
+        format.format(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn interpret_with_format(input: &str, format: &FormatDescription) -> Result<Self, HTParseError> {
+        format.interpret(input)
     }
 
     pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        HTDate::interpret_string_with(input, &HTParseOptions::default())
+    }
+
+    pub fn interpret_string_with(input: &str, options: &HTParseOptions) -> Result<Self, HTParseError> {
+        HTDate::interpret_counted(input, options, &mut HTLeniencyCounts::default(), &StandardCalendar)
+    }
+
+    // the same layouts for a homebrew calendar, with months and day ranges checked against its
+    // tables instead of MONTH_ORDER and days_in_month
+    pub fn interpret_string_in<C: HTCalendar + ?Sized>(input: &str, calendar: &C) -> Result<Self, HTParseError> {
+        HTDate::interpret_counted(input, &HTParseOptions::default(), &mut HTLeniencyCounts::default(), calendar)
+    }
+
+    fn interpret_counted<C: HTCalendar + ?Sized>(input: &str, options: &HTParseOptions, counts: &mut HTLeniencyCounts, calendar: &C) -> Result<Self, HTParseError> {
+        // every layout is short, so the lenient rewrites happen in a copy on the stack. anything
+        // longer than the copy can't match a layout whatever its case
+        let mut buf = [0u8; 32];
+        let mut normalized = input;
+        let mut used = HTLeniencyCounts::default();
+        let lowercase = options.allow_lowercase && input.bytes().any(|b| b.is_ascii_lowercase());
+        let separator = match input.len() {
+            17 => Some(8),
+            19 => Some(10),
+            _ => None,
+        };
+        let separator = separator.filter(|i| options.allow_space_separator && input.as_bytes()[*i] == b' ');
+        if (lowercase || separator.is_some()) && input.len() <= buf.len() {
+            let copy = &mut buf[..input.len()];
+            copy.copy_from_slice(input.as_bytes());
+            if lowercase {
+                copy.make_ascii_uppercase();
+                used.lowercase = 1;
+            }
+            if let Some(index) = separator {
+                copy[index] = b'T';
+                used.space_separator = 1;
+            }
+            // only ascii bytes changed, so it's still utf-8
+            normalized = core::str::from_utf8(copy).unwrap_or(input);
+        }
+        let date = HTDate::interpret_layout(normalized, options, calendar)?;
+        counts.add(&used);
+        Ok(date)
+    }
+
+    fn interpret_layout<C: HTCalendar + ?Sized>(input: &str, options: &HTParseOptions, calendar: &C) -> Result<Self, HTParseError> {
+        if options.exact_separators && !grammar::accepts(input) {
+            return Err(HTParseError::UnrecognizedLayout);
+        }
         // string may be in the format of "YYYY-GM-DDTSSSRRRRR" or "YYYY-GM-DD"
         // or it may not have dashes, in which case assume it's either "YYYYGMDDTSSSRRRRR" or "YYYYGMDD"
-        let mut year = 0;
-        let mut month = (MonthStatus::Greater, Month::Zero);
-        let mut day = 0;
+        let year;
+        let month;
+        let day;
         let mut second = 0u128;
+        // never panic on short strings or ones with multibyte characters
+        let slice = |start: usize, end: usize| input.get(start..end).ok_or(HTParseError::MalformedString);
 
         if input.len() > 8 { // cannot be YYYYGMDD
             match input.len() {
                 17 => { // YYYYGMDDTNNSNNNNR
-                    let year_str = &input[0..4]; // 4
-                    let gl_str = &input[4..5]; // 1
-                    let month_str = &input[5..6]; // 1
-                    let day_str = &input[6..8]; // 2
-                    let sks_str = &input[9..11]; // 2
-                    let rem_str = &input[12..16]; // 4
+                    let year_str = slice(0, 4)?; // 4
+                    let gl_str = slice(4, 5)?; // 1
+                    let month_str = slice(5, 6)?; // 1
+                    let day_str = slice(6, 8)?; // 2
+                    let sks_str = slice(9, 11)?; // 2
+                    let rem_str = slice(12, 16)?; // 4
                     year = year_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     month = parse_month_from_gl_and_m(gl_str, month_str)?;
                     day = day_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     second = sks_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     second *= 6000;
-                    second += rem_str.parse::<u128>().map_err(|_| HTParseError::MalformedString)?;
+                    second += parse_remainder(rem_str)?;
                 }
                 10 => { // YYYY-GM-DD
-                    let year_str = &input[0..4];
-                    let gl_str = &input[5..6];
-                    let month_str = &input[6..7];
-                    let day_str = &input[8..10];
+                    let year_str = slice(0, 4)?;
+                    let gl_str = slice(5, 6)?;
+                    let month_str = slice(6, 7)?;
+                    let day_str = slice(8, 10)?;
                     year = year_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     month = parse_month_from_gl_and_m(gl_str, month_str)?;
                     day = day_str.parse().map_err(|_| HTParseError::MalformedString)?;
                 }
                 19 => { // YYYY-GM-DDTNNSNNNNR
-                    let year_str = &input[0..4]; // 4 -
-                    let gl_str = &input[5..6]; // 1
-                    let month_str = &input[6..7]; // 1 -
-                    let day_str = &input[8..10]; // 2
-                    let sks_str = &input[11..13]; // 2 T
-                    let rem_str = &input[14..18]; // 5
+                    let year_str = slice(0, 4)?; // 4 -
+                    let gl_str = slice(5, 6)?; // 1
+                    let month_str = slice(6, 7)?; // 1 -
+                    let day_str = slice(8, 10)?; // 2
+                    let sks_str = slice(11, 13)?; // 2 T
+                    let rem_str = slice(14, 18)?; // 5
                     year = year_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     month = parse_month_from_gl_and_m(gl_str, month_str)?;
                     day = day_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     second = sks_str.parse().map_err(|_| HTParseError::MalformedString)?;
                     second *= 6000;
-                    second += rem_str.parse::<u128>().map_err(|_| HTParseError::MalformedString)?;
+                    second += parse_remainder(rem_str)?;
                 }
 
                 _ => {
@@ -153,29 +1786,32 @@ impl HTDate {
                 }
             }
 
-            Ok(HTDate {
+            // day 1 up to days_in_month and a second within the day, for every layout
+            HTDate {
                 year,
                 month,
                 day,
                 second,
-            })
+            }
+            .validated_in(calendar)
         } else { // most likely YYYYGMDD
-            let year_str = &input[0..4];
-            let gl_str = &input[4..5];
-            let month_str = &input[5..6];
-            let day_str = &input[6..8];
+            if options.exact_compact_length && input.len() != 8 {
+                return Err(HTParseError::UnrecognizedLayout);
+            }
+            let year_str = slice(0, 4)?;
+            let gl_str = slice(4, 5)?;
+            let month_str = slice(5, 6)?;
+            let day_str = slice(6, 8)?;
             year = year_str.parse().map_err(|_| HTParseError::MalformedString)?;
             month = parse_month_from_gl_and_m(gl_str, month_str)?;
             day = day_str.parse().map_err(|_| HTParseError::MalformedString)?;
-            if day > 24 {
-                return Err(HTParseError::TooManyDays);
-            }
-            Ok(HTDate {
+            HTDate {
                 year,
                 month,
                 day,
                 second,
-            })
+            }
+            .validated_in(calendar)
         }
     }
 }
@@ -241,6 +1877,753 @@ mod tests {
         assert!(date2.is_err());
     }
 
+    #[test]
+    fn detects_formats() {
+        assert_eq!(HTDate::detect_format("2019GA01"), Some(HTFormat::Compact));
+        assert_eq!(HTDate::detect_format("2019-GA-01"), Some(HTFormat::Dashed));
+        assert_eq!(HTDate::detect_format("2019GA01T31S2000R"), Some(HTFormat::CompactWithTime));
+        assert_eq!(HTDate::detect_format("2019-GA-01T31S2000R"), Some(HTFormat::DashedWithTime));
+        assert_eq!(HTDate::detect_format("2019GA01T31S2000"), None);
+        assert_eq!(HTDate::detect_format("2019XA01"), None);
+        assert_eq!(HTDate::detect_format("2019-GA-01X31S2000R"), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn detects_format_of_own_strings() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 12, 1234);
+        assert_eq!(HTDate::detect_format(&date.to_string()), Some(HTFormat::DashedWithTime));
+        assert_eq!(HTDate::detect_format(&date.to_string_no_secs()), Some(HTFormat::Dashed));
+    }
+
+    #[test]
+    fn month_order_round_trips() {
+        for (index, month) in MONTH_ORDER.iter().enumerate() {
+            assert_eq!(month_index(*month) as usize, index);
+            assert_eq!(month_from_index(index as u8), Some(*month));
+        }
+        assert_eq!(month_from_index(MONTHS_PER_YEAR), None);
+    }
+
+    #[test]
+    fn order_arrays_match_index_functions() {
+        for (i, month) in Month::ORDER.iter().enumerate() {
+            assert_eq!(month.index() as usize, i);
+            assert_eq!(<Month as MonthExt>::from_index(i as u8), Some(*month));
+        }
+        for (i, status) in MonthStatus::ORDER.iter().enumerate() {
+            assert_eq!(status.index() as usize, i);
+            assert_eq!(<MonthStatus as MonthStatusExt>::from_index(i as u8), Some(*status));
+        }
+        assert_eq!(<Month as MonthExt>::from_index(5), None);
+        assert_eq!(<MonthStatus as MonthStatusExt>::from_index(2), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn converts_week_dates() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 15, 0);
+        assert_eq!(date.week_of_year(), 7);
+        assert_eq!(date.day_of_week(), 3);
+        assert_eq!(date.to_week_date_string(), "2019-W07-3");
+        assert_eq!(HTDate::from_week_date(2019, 7, 3), Ok(date));
+        assert_eq!(HTDate::interpret_week_string("2019-W07-3"), Ok(date));
+        assert_eq!(HTDate::detect_format("2019-W07-3"), Some(HTFormat::WeekDate));
+
+        let last = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0);
+        assert_eq!(last.week_of_year(), WEEKS_PER_YEAR);
+        assert_eq!(HTDate::from_week_date(2019, WEEKS_PER_YEAR, 6), Ok(last));
+        assert!(HTDate::from_week_date(2019, WEEKS_PER_YEAR + 1, 1).is_err());
+        assert!(HTDate::interpret_week_string("2019-W07-7").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn converts_ordinals() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 9, 0);
+        assert_eq!(date.ordinal(), 57);
+        assert_eq!(date.to_ordinal_string(), "2019-057");
+        assert_eq!(HTDate::from_ordinal(2019, 57), Ok(date));
+        assert_eq!(HTDate::interpret_ordinal_string("2019-057"), Ok(date));
+        assert_eq!(HTDate::detect_format("2019-057"), Some(HTFormat::Ordinal));
+
+        let last = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0);
+        assert_eq!(last.ordinal(), DAYS_PER_YEAR);
+        assert_eq!(HTDate::from_ordinal(2019, DAYS_PER_YEAR), Ok(last));
+        assert!(HTDate::from_ordinal(2019, 0).is_err());
+        assert!(HTDate::interpret_ordinal_string("2019-241").is_err());
+    }
+
+    #[test]
+    fn lenient_ordinals_accept_day_words() {
+        let date = HTDate::from_ordinal(2019, 57).unwrap();
+        assert_eq!(HTDate::interpret_ordinal_string("2019 day 57"), Ok(date));
+        assert_eq!(HTDate::interpret_ordinal_string("2019  Day 057"), Ok(date));
+        assert!(HTDate::interpret_ordinal_string("2019 day 241").is_err());
+        assert!(HTDate::interpret_ordinal_string("2019 day +5").is_err());
+        assert!(HTDate::interpret_ordinal_string("2019 week 5").is_err());
+        assert!(HTDate::interpret_ordinal_string_with("2019 day 57", &HTParseOptions::STRICT).is_err());
+        assert_eq!(HTDate::interpret_ordinal_string_with("2019-057", &HTParseOptions::STRICT), Ok(date));
+    }
+
+    #[test]
+    fn displays_canonical_form() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(date.to_string(), "2019-GA-01T31S2000R");
+        assert_eq!(format!("{:>21}", date), "  2019-GA-01T31S2000R");
+        assert_eq!(format!("{:#}", date), "Greater Apress 1, year 2019, 31 sks 2000 rem");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn small_precisions_limit_remainder_digits() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2057);
+        assert_eq!(format!("{:.4}", date), "2019-GA-01T31S2057R");
+        assert_eq!(format!("{:.2}", date), "2019-GA-01T31S20R");
+        assert_eq!(format!("{:.1}", date), "2019-GA-01T31S2R");
+        assert_eq!(format!("{:.0}", date), "2019-GA-01T31S");
+        assert_eq!(format!("{:#.0}", date), "Greater Apress 1, year 2019, 31 sks");
+        assert_eq!(format!("{:#.2}", date), "Greater Apress 1, year 2019, 31 sks 20 rem");
+        assert_eq!(format!("{:>18.0}", date), "    2019-GA-01T31S");
+        let options = HTFormatOptions {
+            remainder_digits: 1,
+            ..HTFormatOptions::COMPACT
+        };
+        assert_eq!(date.to_string_with(&options), "2019GA01T31S2R");
+    }
+
+    #[cfg(not(feature = "derive_debug"))]
+    #[test]
+    fn debug_shows_canonical_string() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(format!("{:?}", date), "HTDate(2019-GA-01T31S2000R, second: 188000)");
+        assert_eq!(format!("{:#?}", date), "HTDate(2019-GA-01T31S2000R, second: 188000)");
+        assert_eq!(format!("{:?}", Some(date)), "Some(HTDate(2019-GA-01T31S2000R, second: 188000))");
+        // past 99 sks the string can't show the time, the raw count still does
+        let overflowing = HTDate { second: SECONDS_PER_DAY + 5, ..date };
+        assert!(format!("{:?}", overflowing).ends_with(&format!("second: {})", SECONDS_PER_DAY + 5)));
+    }
+
+    #[test]
+    fn packed_bytes_round_trip() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Smosh, 17, 31 * 6000 + 2000);
+        let bytes = date.to_bytes().unwrap();
+        assert_eq!(bytes, [0xe3, 0x07, 0, 0, 0, 0, 0, 0, 8, 17, 0x60, 0xde, 0x02, 0, 0, 0]);
+        assert_eq!(HTDate::decode_packed(&bytes), Ok(date));
+        let mut reserved = bytes;
+        reserved[15] = 1;
+        assert_eq!(HTDate::decode_packed(&reserved), Err(HTParseError::MalformedString));
+        let mut bad_day = bytes;
+        bad_day[9] = 25;
+        assert_eq!(HTDate::decode_packed(&bad_day), Err(HTParseError::TooManyDays));
+        let huge = HTDate::new(u64::MAX as u128 + 1, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(huge.to_bytes(), Err(HTParseError::OtherwiseInvalidDate));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base36_ids_round_trip_and_sort() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let id = date.to_base36().unwrap();
+        assert_eq!(id.len(), 9);
+        assert_eq!(HTDate::from_base36(&id), Ok(date));
+        assert_eq!(HTDate::from_epoch(0).to_base36().unwrap(), "10");
+        assert_eq!(HTDate::from_epoch(36).to_base36().unwrap(), "210");
+        let mut ids: Vec<String> = [0, 35, 36, 1_000_000, 290_000_000_000, u128::MAX]
+            .iter()
+            .map(|ticks| HTDate::from_epoch(*ticks).to_base36().unwrap())
+            .collect();
+        let in_order = ids.clone();
+        ids.sort();
+        assert_eq!(ids, in_order);
+        assert_eq!(HTDate::from_base36(&in_order[5]), Ok(HTDate::from_epoch(u128::MAX)));
+        assert_eq!(HTDate::from_base36("20a"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::from_base36("3a"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::from_base36("2A0"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::from_base36(""), Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    fn adds_and_subtracts_years() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 100);
+        assert_eq!(date.add_years_checked(5), Some(HTDate::new(2024, MonthStatus::Lesser, Month::Funny, 24, 100)));
+        assert_eq!(date.sub_years_checked(2019), Some(HTDate::new(0, MonthStatus::Lesser, Month::Funny, 24, 100)));
+        assert_eq!(date.sub_years_checked(2020), None);
+        assert_eq!(date.add_years_checked(u128::MAX), None);
+        assert_eq!(date.sub_years_checked(7), date.checked_add_years(-7, DayPolicy::Reject));
+        let invalid = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 25, 0);
+        assert_eq!(invalid.add_years_checked(1), None);
+    }
+
+    #[test]
+    fn formats_into_byte_buffers() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let mut buf = [0u8; 32];
+        let len = date.format_into(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"2019-GA-01T31S2000R");
+        assert_eq!(date.format_into(&mut buf[..19]), Ok(19));
+        assert_eq!(date.format_into(&mut buf[..18]), Err(BufferTooSmall { needed: 19 }));
+    }
+
+    #[test]
+    fn array_strings_match_to_string() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let string = date.to_array_string();
+        assert_eq!(string, "2019-GA-01T31S2000R");
+        assert_eq!(string.len(), 19);
+        assert_eq!(format!("[{:>20}]", string), "[ 2019-GA-01T31S2000R]");
+        let huge = HTDate::new(u128::MAX, MonthStatus::Lesser, Month::Funny, 255, u128::MAX);
+        assert_eq!(huge.to_array_string().as_str(), huge.to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn writes_day_ordinals() {
+        let ordinals: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 24]
+            .iter()
+            .map(|day| HTDate::new(2019, MonthStatus::Greater, Month::Apress, *day, 0).day_ordinal_string())
+            .collect();
+        assert_eq!(ordinals, ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "24th"]);
+    }
+
+    #[test]
+    fn subtracts_dates() {
+        let a = HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 100);
+        let b = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0);
+        assert_eq!(b - a, HTDuration::from_ticks(SECONDS_PER_DAY as i128 - 100));
+        assert_eq!(a - b, HTDuration::from_ticks(100 - SECONDS_PER_DAY as i128));
+        assert_eq!(a - a, HTDuration::ZERO);
+        assert_eq!(a.checked_add_duration(b - a), Some(b));
+        let huge = HTDate::new(u128::MAX, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(huge.checked_duration_since(&a), None);
+        let far = HTDate::from_epoch(u128::MAX);
+        assert_eq!(far.checked_duration_since(&HTDate::from_epoch(0)), None);
+        assert_eq!(HTDate::from_epoch(0).checked_duration_since(&HTDate::from_epoch(i128::MAX as u128 + 1)), Some(HTDuration::from_ticks(i128::MIN)));
+    }
+
+    #[test]
+    fn adds_and_subtracts_days() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Funny, 20, 1234);
+        assert_eq!(date.checked_add_days(4), Some(HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 1234)));
+        assert_eq!(date.checked_add_days(5), Some(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 1234)));
+        assert_eq!(date.checked_add_days(DAYS_PER_YEAR as u128), Some(HTDate::new(2020, MonthStatus::Greater, Month::Funny, 20, 1234)));
+        let new_year = HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(new_year.checked_sub_days(1), Some(HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0)));
+        let first = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(first.checked_sub_days(1), None);
+        let last = HTDate::new(u128::MAX / DAYS_PER_YEAR as u128, MonthStatus::Lesser, Month::Funny, 24, 0);
+        assert_eq!(last.checked_add_days(u128::MAX), None);
+    }
+
+    #[test]
+    fn adds_seconds_with_rollover() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, SECONDS_PER_DAY - 1);
+        assert_eq!(date.add_seconds(1), HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 0));
+        assert_eq!(date.add_seconds(-(SECONDS_PER_DAY as i128)), HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 23, SECONDS_PER_DAY - 1));
+        assert_eq!(HTDate::from_epoch(0).checked_add_seconds(-1), None);
+        let overfull = HTDate::new(2019, MonthStatus::Greater, Month::Zero, 1, SECONDS_PER_DAY + 5);
+        assert!(!overfull.is_valid());
+        assert_eq!(overfull.add_seconds(0), HTDate::new(2019, MonthStatus::Greater, Month::Zero, 2, 5));
+        assert!(overfull.add_seconds(0).is_valid());
+        assert!(!HTDate::new(2019, MonthStatus::Greater, Month::Zero, 0, 0).is_valid());
+    }
+
+    #[test]
+    fn saturates_and_wraps() {
+        let first = HTDate::from_epoch(0);
+        let last = HTDate::from_epoch(u128::MAX);
+        assert_eq!(first.saturating_add_seconds(-1), first);
+        assert_eq!(last.saturating_add_seconds(1), last);
+        assert_eq!(first.wrapping_add_seconds(-1), last);
+        assert_eq!(last.wrapping_add_seconds(1), first);
+        assert_eq!(first.add_seconds(5).saturating_add_seconds(-3), first.add_seconds(2));
+        let noon = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 300_000);
+        assert_eq!(noon.saturating_sub_days(3), noon);
+        assert_eq!(noon.wrapping_sub_days(1).wrapping_add_days(1), noon);
+        assert_eq!(noon.saturating_add_days(1).day, 2);
+        assert_eq!(noon.saturating_add_days(u128::MAX), HTDate::from_day_number_at(u128::MAX, 300_000));
+    }
+
+    #[test]
+    fn adds_months_in_calendar_order() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 24, 77);
+        assert_eq!(date.add_months(1, DayPolicy::Reject), Some(HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 77)));
+        assert_eq!(date.add_months(2, DayPolicy::Reject), Some(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 24, 77)));
+        assert_eq!(date.add_months(7, DayPolicy::Reject), Some(HTDate::new(2020, MonthStatus::Greater, Month::Zero, 24, 77)));
+        assert_eq!(date.sub_months(4, DayPolicy::Reject), Some(HTDate::new(2018, MonthStatus::Lesser, Month::Funny, 24, 77)));
+        assert_eq!(date.add_months(-20193, DayPolicy::Reject), Some(HTDate::new(0, MonthStatus::Greater, Month::Zero, 24, 77)));
+        assert_eq!(date.add_months(-20194, DayPolicy::Reject), None);
+        let invalid = HTDate::new(2019, MonthStatus::Greater, Month::Zero, 30, 0);
+        assert_eq!(invalid.add_months(1, DayPolicy::Reject), None);
+        assert_eq!(invalid.add_months(1, DayPolicy::Clamp), Some(HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 24, 0)));
+    }
+
+    #[test]
+    fn adds_signed_years_with_policy() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 3, 9);
+        assert_eq!(date.add_years(-19, DayPolicy::Reject), HTDate::new(2000, MonthStatus::Lesser, Month::Apress, 3, 9));
+        assert_eq!(date.checked_add_years(-2020, DayPolicy::Clamp), None);
+        assert_eq!(date.saturating_add_years(-5000).year, 0);
+        assert_eq!(date.saturating_add_years(i128::MAX).saturating_add_years(i128::MAX).year, u128::MAX);
+        let invalid = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 0, 9);
+        assert_eq!(invalid.checked_add_years(1, DayPolicy::Reject), None);
+        assert_eq!(invalid.checked_add_years(1, DayPolicy::Clamp).map(|d| d.day), Some(1));
+        assert_eq!(invalid.saturating_add_years(1).day, 1);
+    }
+
+    #[test]
+    fn finds_period_bounds() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 13, 123_456);
+        assert_eq!(date.start_of_day(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 13, 0));
+        assert_eq!(date.end_of_day(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 13, 599_999));
+        assert_eq!(date.start_of_month(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 0));
+        assert_eq!(date.end_of_month(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 24, 599_999));
+        assert_eq!(date.start_of_year(), HTDate::new(2019, MonthStatus::Greater, Month::Zero, 1, 0));
+        assert_eq!(date.end_of_year(), HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 599_999));
+        assert_eq!(date.end_of_year().add_seconds(1), date.start_of_year().add_years(1, DayPolicy::Reject));
+    }
+
+    #[test]
+    fn rounds_to_units_and_multiples() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 3000);
+        assert_eq!(date.floor_to(Granularity::Sks), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000)));
+        assert_eq!(date.ceil_to(Granularity::Sks), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 32 * 6000)));
+        assert_eq!(date.round_to(Granularity::Sks), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 32 * 6000)));
+        assert_eq!(date.round_to(Granularity::Day), Some(date.start_of_day()));
+        assert_eq!(date.ceil_to(Granularity::Month), Some(HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 0)));
+        assert_eq!(date.floor_to(Granularity::Year), Some(date.start_of_year()));
+        let ten_sks = HTDuration::from_sks(10);
+        assert_eq!(date.round_to_multiple(ten_sks), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 30 * 6000)));
+        assert_eq!(date.ceil_to_multiple(ten_sks), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 40 * 6000)));
+        assert_eq!(date.floor_to_multiple(HTDuration::ZERO), None);
+        let exact = date.floor_to(Granularity::Sks).unwrap();
+        assert_eq!(exact.ceil_to(Granularity::Sks), Some(exact));
+        assert_eq!(HTDate::from_epoch(u128::MAX).ceil_to(Granularity::Day), None);
+    }
+
+    #[test]
+    fn orders_chronologically() {
+        let mut dates = [
+            HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 0),
+            HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0),
+            HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 1),
+            HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 0),
+            HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 2, 0),
+        ];
+        dates.sort();
+        let epochs: Vec<u128> = dates.iter().map(|d| d.checked_epoch().unwrap()).collect();
+        assert!(epochs.windows(2).all(|w| w[0] < w[1]));
+        let set: std::collections::BTreeSet<HTDate> = dates.iter().copied().collect();
+        assert_eq!(set.iter().next(), Some(&HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 2, 0)));
+    }
+
+    #[test]
+    fn has_extreme_constants() {
+        assert_eq!(HTDate::from_epoch(0), HTDate::EPOCH);
+        assert!(HTDate::MIN.is_valid() && HTDate::MAX.is_valid());
+        assert!(HTDate::from_epoch(u128::MAX) < HTDate::MAX);
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        assert_eq!(date.clamp(HTDate::EPOCH, HTDate::MAX), date);
+        let window_end = HTDate::new(2000, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(date.clamp(HTDate::EPOCH, window_end), window_end);
+        let messy = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 0, SECONDS_PER_DAY * 3);
+        assert_eq!(messy.clamped(), HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, SECONDS_PER_DAY - 1));
+    }
+
+    #[test]
+    fn converts_day_numbers() {
+        assert_eq!(HTDate::EPOCH.to_day_number(), Some(0));
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 500);
+        let day_number = date.to_day_number().unwrap();
+        assert_eq!(day_number, 2019 * 240 + 120);
+        assert_eq!(HTDate::from_day_number(day_number), Some(date.start_of_day()));
+        assert_eq!(HTDate::from_day_number(day_number + 1).unwrap().day, 2);
+        assert_eq!(HTDate::from_day_number(-1), None);
+        assert_eq!(HTDate::MAX.to_day_number(), None);
+    }
+
+    #[test]
+    fn converts_epoch_seconds() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 500);
+        let seconds = date.to_epoch_seconds().unwrap();
+        assert_eq!(seconds, date.to_day_number().unwrap() * SECONDS_PER_DAY as i128 + 500);
+        assert_eq!(HTDate::from_epoch_seconds(seconds), Some(date));
+        assert_eq!(HTDate::from_epoch_seconds(seconds + 100).map(|d| d - date), Some(HTDuration::from_ticks(100)));
+        assert_eq!(HTDate::from_epoch_seconds(-1), None);
+        assert_eq!(HTDate::from_epoch(u128::MAX).to_epoch_seconds(), None);
+    }
+
+    #[test]
+    fn month_lengths_add_up_to_the_year() {
+        let total: u16 = MONTH_ORDER.iter().map(|(status, month)| days_in_month(2019, *status, *month) as u16).sum();
+        assert_eq!(total, DAYS_PER_YEAR);
+        assert_eq!(HTDate::interpret_string("2019GA25"), Err(HTParseError::TooManyDays));
+    }
+
+    #[test]
+    fn validates_days_and_seconds_in_every_layout() {
+        for layout in ["2019GA{d}", "2019-GA-{d}", "2019GA{d}T31S2000R", "2019-GA-{d}T31S2000R"] {
+            let with_day = |day: &str| layout.replace("{d}", day);
+            assert!(HTDate::interpret_string(&with_day("24")).is_ok(), "{}", layout);
+            assert_eq!(HTDate::interpret_string(&with_day("25")), Err(HTParseError::TooManyDays), "{}", layout);
+            assert_eq!(HTDate::interpret_string(&with_day("00")), Err(HTParseError::OtherwiseInvalidDate), "{}", layout);
+        }
+        // the last sks is 99S, but 9999R runs past the end of it and of the day
+        assert_eq!(HTDate::interpret_string("2019GA24T99S9999R"), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::interpret_string("2019-GA-24T99S9999R"), Err(HTParseError::OtherwiseInvalidDate));
+        assert!(HTDate::interpret_string("2019-GA-24T99S0000R").is_ok());
+        // a remainder of a whole sks or more would carry into the next sks and not round trip
+        for input in ["2019-GA-01T31S9999R", "2019GA01T31S6000R"] {
+            assert_eq!(HTDate::interpret_string(input), Err(HTParseError::OtherwiseInvalidDate), "{}", input);
+            assert_eq!(HTDate::interpret_string_with(input, &HTParseOptions::STRICT), Err(HTParseError::OtherwiseInvalidDate), "{}", input);
+        }
+        assert_eq!(HTDate::interpret_string("2019GA01T31S5999R").map(|d| d.second), Ok(31 * 6000 + 5999));
+    }
+
+    #[test]
+    fn follows_the_day_cycle() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 5, 100);
+        assert_eq!(date.day_of_cycle(), CycleDay::Fifthday);
+        assert_eq!(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, 0).day_of_cycle(), CycleDay::Restday);
+        assert_eq!(date.next_occurrence_of(CycleDay::Restday), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 6, 100)));
+        assert_eq!(date.next_occurrence_of(CycleDay::Secondday), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 8, 100)));
+        assert_eq!(date.next_occurrence_of(CycleDay::Fifthday), Some(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 11, 100)));
+        let month_end = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0);
+        assert_eq!(month_end.next_occurrence_of(CycleDay::Firstday), Some(HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 0)));
+        assert_eq!(CycleDay::from_number(6), Some(CycleDay::Restday));
+        assert_eq!(CycleDay::from_number(0), None);
+    }
+
+    #[test]
+    fn adds_durations_with_operators() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 23, 100);
+        let later = date + HTDuration::from_days(3);
+        assert_eq!(later, HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 2, 100));
+        assert_eq!(later - HTDuration::from_days(3), date);
+        assert_eq!(later - date, HTDuration::from_days(3));
+        let mut moving = date;
+        moving += HTDuration::from_sks(1);
+        moving -= HTDuration::from_ticks(6100);
+        assert_eq!(moving, date.start_of_day());
+    }
+
+    #[test]
+    fn replaces_fields_with_validation() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, 100);
+        assert_eq!(date.with_year(2020), Ok(HTDate::new(2020, MonthStatus::Greater, Month::Apress, 24, 100)));
+        assert_eq!(date.with_month(MonthStatus::Lesser, Month::Zero), Ok(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 24, 100)));
+        assert_eq!(date.with_day(3).map(|d| d.day), Ok(3));
+        assert_eq!(date.with_second(599_999).map(|d| d.second), Ok(599_999));
+        assert_eq!(date.with_day(25), Err(HTParseError::TooManyDays));
+        assert_eq!(date.with_day(0), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(date.with_second(SECONDS_PER_DAY), Err(HTParseError::OtherwiseInvalidDate));
+        let broken = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 30, 0);
+        assert_eq!(broken.with_year(2020), Err(HTParseError::TooManyDays));
+    }
+
+    #[test]
+    fn steps_a_day_at_a_time() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 100);
+        assert_eq!(date.succ_day(), Some(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 100)));
+        assert_eq!(date.succ_day().and_then(|d| d.pred_day()), Some(date));
+        assert_eq!(HTDate::MIN.pred_day(), None);
+        assert_eq!(HTDate::MAX.succ_day(), None);
+    }
+
+    #[test]
+    fn normalizes_out_of_range_fields() {
+        let overflowing = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 30, 2 * SECONDS_PER_DAY + 7);
+        assert!(!overflowing.is_normalized());
+        let normalized = overflowing.normalize().unwrap();
+        assert_eq!(normalized, HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 8, 7));
+        assert!(normalized.is_normalized());
+        assert_eq!(normalized.normalize(), Some(normalized));
+        let day_zero = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 0, 0);
+        assert_eq!(day_zero.normalize(), Some(HTDate::new(2019, MonthStatus::Greater, Month::Funny, 24, 0)));
+        assert_eq!(HTDate::new(0, MonthStatus::Greater, Month::Zero, 0, 0).normalize(), None);
+        assert_eq!(HTDate { second: SECONDS_PER_DAY, ..HTDate::MAX }.normalize(), None);
+        // the year's first day is 15 short of u128::MAX days, so day 20 overflows counting them
+        assert_eq!(HTDate::new(u128::MAX / DAYS_PER_YEAR as u128, MonthStatus::Greater, Month::Zero, 20, 0).normalize(), None);
+    }
+
+    #[test]
+    fn checks_fields_on_construction() {
+        assert_eq!(HTDate::new_checked(2019, MonthStatus::Greater, Month::Apress, 24, 599_999), Ok(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, 599_999)));
+        assert_eq!(HTDate::new_checked(2019, MonthStatus::Lesser, Month::Funny, 200, 0), Err(HTParseError::TooManyDays));
+        assert_eq!(HTDate::new_checked(2019, MonthStatus::Lesser, Month::Funny, 0, 0), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::new_checked(2019, MonthStatus::Lesser, Month::Funny, 1, u128::MAX), Err(HTParseError::OtherwiseInvalidDate));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn converts_with_try_from() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(HTDate::try_from("2019-GA-01T31S2000R"), Ok(date));
+        assert_eq!(HTDate::try_from(date.to_string()), Ok(date));
+        assert_eq!(HTDate::try_from((2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000)), Ok(date));
+        assert_eq!(HTDate::try_from((2019, MonthStatus::Greater, Month::Apress, 25, 0)), Err(HTParseError::TooManyDays));
+        let converted: Result<HTDate, _> = "not a date".try_into();
+        assert_eq!(converted, Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    #[cfg(feature = "ht_cal")]
+    fn converts_to_and_from_hdatetime() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let hdt: HDateTime = date.into();
+        assert_eq!(HTDate::from(&hdt), date);
+        assert_eq!(HTDate::from(HDateTime::from(&date)), date);
+    }
+
+    #[test]
+    fn builds_dates_in_const_context() {
+        const START: HTDate = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        const CHECKED: Result<HTDate, HTParseError> = HTDate::new_checked(2019, MonthStatus::Greater, Month::Apress, 25, 0);
+        const LETTERS: (char, char) = (status_letter(START.month.0), month_letter(START.month.1));
+        assert_eq!(START.to_string(), "2019-GA-01T00S0000R");
+        assert_eq!(CHECKED, Err(HTParseError::TooManyDays));
+        assert_eq!(LETTERS, ('G', 'A'));
+    }
+
+    #[test]
+    fn chains_clamping_modifiers() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 100);
+        assert_eq!(date.with_day_clamped(30).at_time(120, 7000), HTDate::new(2019, MonthStatus::Greater, Month::Apress, 24, SECONDS_PER_DAY - 1));
+        assert_eq!(date.with_day_clamped(0).day, 1);
+        assert_eq!(date.with_month_clamped(MonthStatus::Lesser, Month::Funny).at_time(31, 2000), HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 10, 31 * 6000 + 2000));
+        assert_eq!(date.at_start_of_next_month(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 0));
+        let year_end = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0);
+        assert_eq!(year_end.at_start_of_next_month(), HTDate::new(2020, MonthStatus::Greater, Month::Zero, 1, 0));
+        assert_eq!(HTDate::MAX.at_start_of_next_month(), HTDate::MAX);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn truncates_at_each_granularity() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(date.to_string_truncated(Granularity::Year), "2019");
+        assert_eq!(date.to_string_truncated(Granularity::Month), "2019-GA");
+        assert_eq!(date.to_string_truncated(Granularity::Day), "2019-GA-01");
+        assert_eq!(date.to_string_truncated(Granularity::Sks), "2019-GA-01T31S");
+        assert_eq!(date.to_string_truncated(Granularity::Remainder), date.to_string());
+        assert_eq!(date.to_string_no_secs(), "2019-GA-01");
+    }
+
+    #[test]
+    fn precision_cuts_between_components() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(format!("{:.10}", date), "2019-GA-01");
+        assert_eq!(format!("{:.12}", date), "2019-GA-01");
+        assert_eq!(format!("{:.14}", date), "2019-GA-01T31S");
+        assert_eq!(format!("{:.7}", date), "2019-GA");
+        assert_eq!(format!("{:.5}", date), "2019");
+        assert_eq!(format!("{:.100}", date), "2019-GA-01T31S2000R");
+        assert_eq!(format!("{:>12.10}", date), "  2019-GA-01");
+        assert_eq!(format!("{:#.30}", date), "Greater Apress 1, year 2019");
+        let wide = HTDate::new(123456, MonthStatus::Greater, Month::Apress, 1, 0);
+        assert_eq!(format!("{:.10}", wide), "123456-GA");
+        let wide = HTDate::new(u128::MAX, MonthStatus::Lesser, Month::Funny, u8::MAX, u128::MAX);
+        assert_eq!(wide.to_string(), format!("{}-LF-255T{}S{}R", u128::MAX, u128::MAX / 6000, u128::MAX % 6000));
+        assert_eq!(format!("{:#}", wide), format!("Lesser Funny 255, year {}, {} sks {} rem", u128::MAX, u128::MAX / 6000, u128::MAX % 6000));
+    }
+
+    #[test]
+    fn doesnt_panic_on_short_strings() {
+        assert_eq!(HTDate::interpret_string("2019GA1"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_string(""), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_string("2019GAé"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_string("201é-GA-01"), Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    fn strict_mode_requires_exact_compact_length() {
+        let strict = HTParseOptions::STRICT;
+        assert_eq!(HTDate::interpret_string_with("2019GA1", &strict), Err(HTParseError::UnrecognizedLayout));
+        assert_eq!(HTDate::interpret_string_with("", &strict), Err(HTParseError::UnrecognizedLayout));
+        assert_eq!(HTDate::interpret_string_with("2019GA01", &strict), HTDate::interpret_string("2019GA01"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formats_compact_strings() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(date.to_compact_string(), "2019GA01T31S2000R");
+        assert_eq!(date.to_compact_string_no_secs(), "2019GA01");
+        assert_eq!(HTDate::interpret_string(&date.to_compact_string()), Ok(date));
+        let date_only = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        assert_eq!(HTDate::interpret_string(&date.to_compact_string_no_secs()), Ok(date_only));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn re_emits_detected_formats() {
+        for input in ["2019GA01", "2019-GA-01", "2019GA01T31S2000R", "2019-GA-01T31S2000R", "2019GA01_31S2000R"] {
+            let format = HTDate::detect_format(input).unwrap();
+            let date = HTDate::interpret_string(input).unwrap();
+            assert_eq!(date.to_string_as(format), input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trips_url_safe_strings() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let safe = date.to_url_safe_string();
+        assert_eq!(safe, "2019GA01_31S2000R");
+        assert!(safe.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_'));
+        assert_eq!(HTDate::interpret_url_safe_string(&safe), Ok(date));
+        assert!(HTDate::interpret_url_safe_string("2019GA01T31S2000R").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formats_with_options() {
+        let date = HTDate::new(19, MonthStatus::Lesser, Month::Smosh, 4, 3 * 6000 + 20);
+        assert_eq!(date.to_string_with(&HTFormatOptions::CANONICAL), date.to_string());
+        assert_eq!(date.to_string_no_secs(), "0019-LS-04");
+        let options = HTFormatOptions {
+            year_width: 6,
+            case: LetterCase::Lower,
+            ..HTFormatOptions::COMPACT
+        };
+        assert_eq!(date.to_string_with(&options), "000019ls04t03s0020r");
+        let options = HTFormatOptions {
+            year_width: 0,
+            include_time: false,
+            ..HTFormatOptions::URL_SAFE
+        };
+        assert_eq!(date.to_string_with(&options), "19LS04");
+        let mut out = String::from("> ");
+        date.write_with(&mut out, &HTFormatOptions::URL_SAFE).unwrap();
+        assert_eq!(out, "> 0019LS04_03S0020R");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn writes_prose() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(date.to_prose_string(), "the 1st day of Greater Apress, year 2019, at 31 sks and 2000 remainder");
+        let date = HTDate::new(3, MonthStatus::Lesser, Month::Zero, 12, 5);
+        assert_eq!(date.to_prose_string(), "the 12th day of Lesser Zero, year 3, at 0 sks and 5 remainder");
+        let suffixes: Vec<&str> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 24, 111].iter().map(|n| ordinal_suffix(*n)).collect();
+        assert_eq!(suffixes, ["st", "nd", "rd", "th", "th", "th", "th", "st", "nd", "rd", "th", "th"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writes_into_existing_buffers() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let mut string = String::from("at ");
+        date.write_to(&mut string).unwrap();
+        assert_eq!(string, "at 2019-GA-01T31S2000R");
+        let mut bytes = b"at ".to_vec();
+        date.write_io(&mut bytes).unwrap();
+        assert_eq!(bytes, b"at 2019-GA-01T31S2000R");
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn formats_inline_compact_strs() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let compact = date.to_compact_str();
+        assert_eq!(compact, date.to_string());
+        assert!(!compact.is_heap_allocated());
+    }
+
+    #[test]
+    fn lenient_mode_accepts_lowercase_and_spaces() {
+        let date = HTDate::interpret_string("2019-GA-01T31S2000R").unwrap();
+        assert_eq!(HTDate::interpret_string("2019-ga-01t31s2000r"), Ok(date));
+        assert_eq!(HTDate::interpret_string("2019-GA-01 31S2000R"), Ok(date));
+        assert_eq!(HTDate::interpret_string("2019ga01 31s2000r"), HTDate::interpret_string("2019GA01T31S2000R"));
+        let strict = HTParseOptions::STRICT;
+        assert!(HTDate::interpret_string_with("2019-ga-01T31S2000R", &strict).is_err());
+        assert!(HTDate::interpret_string_with("2019-GA-01 31S2000R", &strict).is_err());
+        assert!(HTDate::interpret_string_with("2019-GA-01X31S2000R", &strict).is_err());
+        assert_eq!(HTDate::interpret_string_with("2019-GA-01T31S2000R", &strict), Ok(date));
+    }
+
+    #[test]
+    fn sessions_count_leniencies() {
+        let mut session = HTParseSession::new(HTParseOptions::LENIENT);
+        for input in ["2019-ga-01", "2019-GA-01 31S2000R", "2019-ga-01 31s2000r", "2019-GA-01", "nonsense"] {
+            let _ = session.interpret_string(input);
+        }
+        assert_eq!(session.counts(), HTLeniencyCounts { lowercase: 2, space_separator: 2 });
+        assert_eq!(session.counts().to_string(), "lowercase: 2, space_separator: 2");
+        assert_eq!(session.parsed(), 4);
+        session.reset();
+        assert_eq!(session.counts().total(), 0);
+    }
+
+    #[test]
+    fn letter_tables_round_trip() {
+        for (status, month) in MONTH_ORDER {
+            assert_eq!(status_from_letter(status_letter(status)), Some(status));
+            assert_eq!(month_from_letter(month_letter(month)), Some(month));
+            assert_eq!(StatusLetter(status).to_string().parse(), Ok(StatusLetter(status)));
+            assert_eq!(MonthLetter(month).to_string().parse(), Ok(MonthLetter(month)));
+        }
+        assert_eq!(status_from_letter('g'), None);
+        assert!("GA".parse::<StatusLetter>().is_err());
+        assert!("".parse::<MonthLetter>().is_err());
+    }
+
+    #[test]
+    fn epoch_round_trips() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 17, 31 * 6000 + 2000);
+        assert_eq!(HTDate::from_epoch(date.checked_epoch().unwrap()), date);
+        assert_eq!(HTDate::from_epoch(0), HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0));
+        assert_eq!(HTDate::new(u128::MAX, MonthStatus::Greater, Month::Zero, 1, 0).checked_epoch(), None);
+        // seconds past the end of the day carry over
+        let overflowing = HTDate::new(2019, MonthStatus::Greater, Month::Zero, 24, SECONDS_PER_DAY);
+        assert_eq!(HTDate::from_epoch(overflowing.checked_epoch().unwrap()), HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 1, 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sortable_keys_sort_chronologically() {
+        let dates = [
+            HTDate::new(5, MonthStatus::Lesser, Month::Funny, 24, SECONDS_PER_DAY - 1),
+            HTDate::new(2019, MonthStatus::Greater, Month::Zero, 1, 0),
+            HTDate::new(2019, MonthStatus::Greater, Month::Funny, 3, 10),
+            HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 2, 0),
+            HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 2, 1),
+            HTDate::new(123456, MonthStatus::Greater, Month::Zero, 1, 0),
+            HTDate::new(u128::MAX, MonthStatus::Lesser, Month::Funny, 24, SECONDS_PER_DAY - 1),
+        ];
+        let keys: Vec<String> = dates.iter().map(|d| d.to_sortable_key().unwrap()).collect();
+        for pair in keys.windows(2) {
+            assert_eq!(pair[0].len(), pair[1].len());
+            assert!(pair[0] < pair[1]);
+        }
+        for (date, key) in dates.iter().zip(&keys) {
+            assert_eq!(HTDate::from_sortable_key(key), Ok(*date));
+        }
+        assert!(HTDate::new(2019, MonthStatus::Greater, Month::Zero, 1, SECONDS_PER_DAY).to_sortable_key().is_err());
+        assert!(HTDate::from_sortable_key("2019").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compares_in_constant_time() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert!(date.eq_constant_time(&date));
+        for other in [
+            HTDate::new(2018, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000),
+            HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 1, 31 * 6000 + 2000),
+            HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 31 * 6000 + 2000),
+            HTDate::new(2019, MonthStatus::Greater, Month::Apress, 2, 31 * 6000 + 2000),
+            HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2001),
+        ] {
+            assert!(!date.eq_constant_time(&other));
+        }
+        let key = date.to_sortable_key().unwrap();
+        assert!(constant_time_eq(key.as_bytes(), key.as_bytes()));
+        assert!(!constant_time_eq(key.as_bytes(), date.to_string().as_bytes()));
+        assert!(!constant_time_eq(b"2019GA01", b"2019GA02"));
+    }
+
     #[test]
     fn doesnt_allow_days_over_24() {
         let date = "2019GA25";
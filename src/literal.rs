@@ -0,0 +1,98 @@
+use crate::{month_from_letter, status_from_letter, HTDate, HTParseError, SECONDS_PER_SKS};
+
+// htdate!("2019-GA-01T31S2000R") is the date, checked while compiling. a literal that isn't a
+// valid canonical date, with or without the time, fails the build
+#[macro_export]
+macro_rules! htdate {
+    ($literal:literal) => {{
+        const DATE: $crate::HTDate = match $crate::HTDate::interpret_canonical($literal) {
+            Ok(date) => date,
+            Err(_) => panic!(concat!("invalid HT date literal: ", $literal)),
+        };
+        DATE
+    }};
+}
+
+impl HTDate {
+    // only the canonical layout, 2019-GA-01T31S2000R or 2019-GA-01, but usable in const items.
+    // interpret_string takes many more layouts
+    pub const fn interpret_canonical(input: &str) -> Result<Self, HTParseError> {
+        let bytes = input.as_bytes();
+        let mut i = 0;
+        let mut year: u128 = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            year = match year.checked_mul(10) {
+                Some(year) => match year.checked_add((bytes[i] - b'0') as u128) {
+                    Some(year) => year,
+                    None => return Err(HTParseError::OtherwiseInvalidDate),
+                },
+                None => return Err(HTParseError::OtherwiseInvalidDate),
+            };
+            i += 1;
+        }
+        if i == 0 || bytes.len() < i + 6 || bytes[i] != b'-' || bytes[i + 3] != b'-' {
+            return Err(HTParseError::MalformedString);
+        }
+        let (month_status, month) = match (status_from_letter(bytes[i + 1] as char), month_from_letter(bytes[i + 2] as char)) {
+            (Some(month_status), Some(month)) => (month_status, month),
+            _ => return Err(HTParseError::MalformedString),
+        };
+        let day = match digits(bytes, i + 4, 2) {
+            Some(day) => day as u8,
+            None => return Err(HTParseError::MalformedString),
+        };
+        i += 6;
+        let mut second = 0;
+        if i < bytes.len() {
+            // T, sks, S, four digits of remainder, R
+            if bytes.len() != i + 9 || bytes[i] != b'T' || bytes[i + 3] != b'S' || bytes[i + 8] != b'R' {
+                return Err(HTParseError::MalformedString);
+            }
+            second = match (digits(bytes, i + 1, 2), digits(bytes, i + 4, 4)) {
+                (Some(sks), Some(rem)) if rem < SECONDS_PER_SKS => sks * SECONDS_PER_SKS + rem,
+                (Some(_), Some(_)) => return Err(HTParseError::OtherwiseInvalidDate),
+                _ => return Err(HTParseError::MalformedString),
+            };
+        }
+        HTDate::new_checked(year, month_status, month, day, second)
+    }
+}
+
+// count ascii digits starting at start as a number, None if any aren't digits
+const fn digits(bytes: &[u8], start: usize, count: usize) -> Option<u128> {
+    let mut value = 0;
+    let mut i = start;
+    while i < start + count {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[i] - b'0') as u128;
+        i += 1;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    const START: HTDate = htdate!("2019-GA-01T31S2000R");
+
+    #[test]
+    fn reads_literals_at_compile_time() {
+        assert_eq!(START, HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000));
+        assert_eq!(htdate!("2019-LF-24"), HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 24, 0));
+        assert_eq!(HTDate::interpret_canonical(&START.to_string()), Ok(START));
+    }
+
+    #[test]
+    fn rejects_malformed_literals() {
+        assert_eq!(HTDate::interpret_canonical("2019-GA-25"), Err(HTParseError::TooManyDays));
+        assert_eq!(HTDate::interpret_canonical("2019-GA-01T31S6000R"), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTDate::interpret_canonical("2019-GX-01"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_canonical("2019GA01"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_canonical("2019-GA-01T31S2000"), Err(HTParseError::MalformedString));
+        assert_eq!(HTDate::interpret_canonical("-GA-01"), Err(HTParseError::MalformedString));
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::{format, string::{String, ToString}};
+use crate::order::{month_number, status_number};
+use crate::{Granularity, HTDate, SECONDS_PER_SKS};
+
+// the words a date is written with, so it can be rendered in languages other than english.
+// names are in order::month_number / status_number order
+#[derive(Debug, Clone, Copy)]
+pub struct HTLocale {
+    pub code: &'static str,
+    pub status_names: [&'static str; 2],
+    pub month_names: [&'static str; 5],
+    pub ordinal_suffix: fn(u128) -> &'static str,
+    // {day}, {ordinal} (day with its suffix), {status}, {month}, {year}, {sks} and {rem} are filled in
+    pub date_template: &'static str,
+    // singular and plural, coarsest unit first like Granularity
+    pub unit_names: [(&'static str, &'static str); 5],
+    // {} is the amount and unit, e.g. "{} ago" and "in {}"
+    pub past: &'static str,
+    pub future: &'static str,
+    pub now: &'static str,
+    // for distance_in_words, {} is the amount and unit or the singular unit name
+    pub about: &'static str,
+    pub less_than: &'static str,
+}
+
+impl HTLocale {
+    pub const ENGLISH: HTLocale = HTLocale {
+        code: "en",
+        status_names: ["Greater", "Lesser"],
+        month_names: ["Zero", "Niktvirin", "Apress", "Smosh", "Funny"],
+        ordinal_suffix: crate::ordinal_suffix,
+        date_template: "the {ordinal} day of {status} {month}, year {year}, at {sks} sks and {rem} remainder",
+        unit_names: [("year", "years"), ("month", "months"), ("day", "days"), ("sks", "sks"), ("rem", "rem")],
+        past: "{} ago",
+        future: "in {}",
+        now: "just now",
+        about: "about {}",
+        less_than: "less than a {}",
+    };
+
+    pub fn format(&self, date: &HTDate) -> String {
+        self.date_template
+            .replace("{day}", &date.day.to_string())
+            .replace("{ordinal}", &self.ordinal(date.day as u128))
+            .replace("{status}", self.status_names[status_number(date.month.0) as usize])
+            .replace("{month}", self.month_names[month_number(date.month.1) as usize])
+            .replace("{year}", &date.year.to_string())
+            .replace("{sks}", &(date.second / SECONDS_PER_SKS).to_string())
+            .replace("{rem}", &(date.second % SECONDS_PER_SKS).to_string())
+    }
+
+    // "23rd" in english
+    pub fn ordinal(&self, n: u128) -> String {
+        format!("{}{}", n, (self.ordinal_suffix)(n))
+    }
+
+    // "3 days" in this locale, for the relative phrases
+    pub fn amount(&self, amount: u128, unit: Granularity) -> String {
+        let (singular, plural) = self.unit_names[unit as usize];
+        format!("{} {}", amount, if amount == 1 { singular } else { plural })
+    }
+}
+
+impl Default for HTLocale {
+    fn default() -> Self {
+        HTLocale::ENGLISH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn english_matches_prose_string() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        assert_eq!(date.format_localized(&HTLocale::ENGLISH), date.to_prose_string());
+    }
+
+    #[test]
+    fn formats_with_other_locales() {
+        let locale = HTLocale {
+            code: "x-test",
+            status_names: ["Grand", "Petit"],
+            month_names: ["Zéro", "Niktvirin", "Apresse", "Smoche", "Drôle"],
+            ordinal_suffix: |_| "e",
+            date_template: "le {ordinal} jour de {month} {status}, an {year}",
+            ..HTLocale::ENGLISH
+        };
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Funny, 3, 0);
+        assert_eq!(date.format_localized(&locale), "le 3e jour de Drôle Petit, an 2019");
+        assert_eq!(date.day_ordinal_string_localized(&locale), "3e");
+        assert_eq!(locale.amount(1, Granularity::Day), "1 day");
+        assert_eq!(locale.amount(2, Granularity::Year), "2 years");
+    }
+}
@@ -0,0 +1,78 @@
+// copies of the ht_cal month enums for builds that don't pull in ht_cal, with conversions to
+// the real ones when both are around
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthStatus {
+    Greater,
+    Lesser,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Month {
+    Zero,
+    Niktvirin,
+    Apress,
+    Smosh,
+    Funny,
+}
+
+#[cfg(feature = "ht_cal")]
+mod ht_cal_conversions {
+    use super::{Month, MonthStatus};
+    use ht_cal::datetime as cal;
+
+    impl From<MonthStatus> for cal::MonthStatus {
+        fn from(status: MonthStatus) -> Self {
+            match status {
+                MonthStatus::Greater => cal::MonthStatus::Greater,
+                MonthStatus::Lesser => cal::MonthStatus::Lesser,
+            }
+        }
+    }
+
+    impl From<cal::MonthStatus> for MonthStatus {
+        fn from(status: cal::MonthStatus) -> Self {
+            match status {
+                cal::MonthStatus::Greater => MonthStatus::Greater,
+                cal::MonthStatus::Lesser => MonthStatus::Lesser,
+            }
+        }
+    }
+
+    impl From<Month> for cal::Month {
+        fn from(month: Month) -> Self {
+            match month {
+                Month::Zero => cal::Month::Zero,
+                Month::Niktvirin => cal::Month::Niktvirin,
+                Month::Apress => cal::Month::Apress,
+                Month::Smosh => cal::Month::Smosh,
+                Month::Funny => cal::Month::Funny,
+            }
+        }
+    }
+
+    impl From<cal::Month> for Month {
+        fn from(month: cal::Month) -> Self {
+            match month {
+                cal::Month::Zero => Month::Zero,
+                cal::Month::Niktvirin => Month::Niktvirin,
+                cal::Month::Apress => Month::Apress,
+                cal::Month::Smosh => Month::Smosh,
+                cal::Month::Funny => Month::Funny,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::MONTH_ORDER;
+
+        #[test]
+        fn converts_to_and_from_ht_cal() {
+            for (status, month) in MONTH_ORDER {
+                assert_eq!(MonthStatus::from(cal::MonthStatus::from(status)), status);
+                assert_eq!(Month::from(cal::Month::from(month)), month);
+            }
+        }
+    }
+}
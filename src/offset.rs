@@ -0,0 +1,258 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::duration::HTDuration;
+use crate::temporal::HTTemporal;
+use crate::{HTDate, HTParseError, Month, MonthStatus, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+// fixed distance from universal time, less than a day either way. written "Z" when zero and
+// "+05S0000R" or "-05S0000R" otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HTOffset {
+    ticks: i32,
+}
+
+impl HTOffset {
+    pub const UNIVERSAL: HTOffset = HTOffset { ticks: 0 };
+
+    pub fn from_ticks(ticks: i64) -> Result<Self, HTParseError> {
+        if ticks.unsigned_abs() as u128 >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(HTOffset { ticks: ticks as i32 })
+    }
+
+    pub fn from_sks(sks: i64) -> Result<Self, HTParseError> {
+        HTOffset::from_ticks(sks.checked_mul(SECONDS_PER_SKS as i64).ok_or(HTParseError::OtherwiseInvalidDate)?)
+    }
+
+    pub fn ticks(&self) -> i64 {
+        self.ticks as i64
+    }
+
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        if input == "Z" {
+            return Ok(HTOffset::UNIVERSAL);
+        }
+        let negative = match input.as_bytes().first() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(HTParseError::MalformedString),
+        };
+        let (sks, rem) = input[1..].strip_suffix('R').and_then(|rest| rest.split_once('S')).ok_or(HTParseError::MalformedString)?;
+        if sks.len() != 2 || rem.len() != 4 || !(sks.bytes().chain(rem.bytes())).all(|c| c.is_ascii_digit()) {
+            return Err(HTParseError::MalformedString);
+        }
+        let sks: i64 = sks.parse().map_err(|_| HTParseError::MalformedString)?;
+        let rem: i64 = rem.parse().map_err(|_| HTParseError::MalformedString)?;
+        if rem >= SECONDS_PER_SKS as i64 {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        let ticks = sks * SECONDS_PER_SKS as i64 + rem;
+        HTOffset::from_ticks(if negative { -ticks } else { ticks })
+    }
+}
+
+impl Display for HTOffset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.ticks == 0 {
+            return f.write_str("Z");
+        }
+        let sign = if self.ticks < 0 { '-' } else { '+' };
+        let ticks = self.ticks.unsigned_abs() as u128;
+        write!(f, "{}{:02}S{:04}R", sign, ticks / SECONDS_PER_SKS, ticks % SECONDS_PER_SKS)
+    }
+}
+
+impl FromStr for HTOffset {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HTOffset::interpret_string(s)
+    }
+}
+
+// a local date and the offset it was written at. equality and ordering go by the instant, so
+// the same moment at two offsets compares equal
+#[derive(Debug, Clone, Copy)]
+pub struct HTDateTimeOffset {
+    date: HTDate,
+    offset: HTOffset,
+}
+
+impl HTDateTimeOffset {
+    pub fn new(date: HTDate, offset: HTOffset) -> Self {
+        HTDateTimeOffset { date, offset }
+    }
+
+    // None if the local date at offset would be before the epoch or past the last date
+    pub fn from_universal(universal: &HTDate, offset: HTOffset) -> Option<Self> {
+        let date = universal.checked_add_duration(HTDuration::from_ticks(offset.ticks() as i128))?;
+        Some(HTDateTimeOffset { date, offset })
+    }
+
+    pub fn date(&self) -> HTDate {
+        self.date
+    }
+
+    pub fn offset(&self) -> HTOffset {
+        self.offset
+    }
+
+    // None if the universal date would be before the epoch or past the last date
+    pub fn to_universal(&self) -> Option<HTDate> {
+        self.date.checked_add_duration(HTDuration::from_ticks(-(self.offset.ticks() as i128)))
+    }
+
+    // the same instant written at another offset
+    pub fn to_offset(&self, offset: HTOffset) -> Option<Self> {
+        HTDateTimeOffset::from_universal(&self.to_universal()?, offset)
+    }
+
+    // a date as HTDate::interpret_string takes it, followed by Z or a signed offset
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        let split = if input.ends_with('Z') {
+            input.len() - 1
+        } else {
+            input.rfind(['+', '-']).ok_or(HTParseError::MalformedString)?
+        };
+        let offset = HTOffset::interpret_string(&input[split..])?;
+        let date = HTDate::interpret_string(&input[..split])?;
+        Ok(HTDateTimeOffset { date, offset })
+    }
+
+    // ticks since the epoch in universal time, offset by a day so the earliest local dates still
+    // have one
+    fn instant_key(&self) -> Option<u128> {
+        self.date.checked_epoch()?.checked_add(SECONDS_PER_DAY)?.checked_add_signed(-(self.offset.ticks() as i128))
+    }
+}
+
+impl PartialEq for HTDateTimeOffset {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HTDateTimeOffset {}
+
+impl PartialOrd for HTDateTimeOffset {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// dates too far out for an epoch sort after all others, by local date then offset
+impl Ord for HTDateTimeOffset {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.instant_key(), other.instant_key()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => (self.date, self.offset).cmp(&(other.date, other.offset)),
+        }
+    }
+}
+
+// the fields are the local date's, to_epoch the universal instant. saturates to 0 before the epoch
+// and to u128::MAX past the end, like the default
+impl HTTemporal for HTDateTimeOffset {
+    fn year(&self) -> u128 {
+        self.date.year
+    }
+
+    fn month(&self) -> (MonthStatus, Month) {
+        self.date.month
+    }
+
+    fn day(&self) -> u8 {
+        self.date.day
+    }
+
+    fn second(&self) -> u128 {
+        self.date.second
+    }
+
+    fn to_htdate(&self) -> HTDate {
+        self.date
+    }
+
+    fn to_epoch(&self) -> u128 {
+        self.instant_key().map_or(u128::MAX, |key| key.saturating_sub(SECONDS_PER_DAY))
+    }
+}
+
+// the canonical date string then the offset, 2019-GA-01T31S2000R+05S0000R
+impl Display for HTDateTimeOffset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.date, self.offset)
+    }
+}
+
+impl FromStr for HTDateTimeOffset {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HTDateTimeOffset::interpret_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_writes_offsets() {
+        let offset = HTOffset::from_ticks(5 * 6000 + 12).unwrap();
+        assert_eq!(offset.to_string(), "+05S0012R");
+        assert_eq!("+05S0012R".parse(), Ok(offset));
+        assert_eq!("-99S5999R".parse::<HTOffset>().map(|o| o.ticks()), Ok(-(SECONDS_PER_DAY as i64) + 1));
+        assert_eq!(HTOffset::UNIVERSAL.to_string(), "Z");
+        assert_eq!("Z".parse(), Ok(HTOffset::UNIVERSAL));
+        assert_eq!(HTOffset::from_sks(100), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!("+5S0000R".parse::<HTOffset>(), Err(HTParseError::MalformedString));
+        assert_eq!("05S0000R".parse::<HTOffset>(), Err(HTParseError::MalformedString));
+    }
+
+    #[test]
+    fn converts_between_offsets() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 3 * 6000);
+        let local = HTDateTimeOffset::new(date, HTOffset::from_sks(5).unwrap());
+        assert_eq!(local.to_universal(), Some(HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 24, SECONDS_PER_DAY - 2 * 6000)));
+        let west = local.to_offset(HTOffset::from_sks(-10).unwrap()).unwrap();
+        assert_eq!(west.date(), HTDate::new(2019, MonthStatus::Greater, Month::Niktvirin, 24, SECONDS_PER_DAY - 12 * 6000));
+        assert_eq!(west, local);
+        assert!(HTDateTimeOffset::new(date, HTOffset::UNIVERSAL) > local);
+        let first = HTDateTimeOffset::new(HTDate::EPOCH, HTOffset::from_sks(1).unwrap());
+        assert_eq!(first.to_universal(), None);
+        assert!(first < HTDateTimeOffset::new(HTDate::EPOCH, HTOffset::UNIVERSAL));
+    }
+
+    fn fields_and_epoch<T: HTTemporal>(value: &T) -> (u8, u128, u128) {
+        (value.day(), value.second(), value.to_epoch())
+    }
+
+    #[test]
+    fn works_as_a_temporal() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 3 * 6000);
+        let local = HTDateTimeOffset::new(date, HTOffset::from_sks(5).unwrap());
+        let universal = local.to_universal().unwrap();
+        assert_eq!(fields_and_epoch(&local), (1, 3 * 6000, universal.to_epoch()));
+        assert_eq!(local.to_htdate(), date);
+        assert_eq!(HTDateTimeOffset::new(HTDate::EPOCH, HTOffset::from_sks(1).unwrap()).to_epoch(), 0);
+        assert_eq!(HTDateTimeOffset::new(HTDate::EPOCH, HTOffset::from_sks(-1).unwrap()).to_epoch(), 6000);
+    }
+
+    #[test]
+    fn round_trips_strings() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        for offset in [HTOffset::UNIVERSAL, HTOffset::from_sks(5).unwrap(), HTOffset::from_ticks(-12).unwrap()] {
+            let zoned = HTDateTimeOffset::new(date, offset);
+            let parsed: HTDateTimeOffset = zoned.to_string().parse().unwrap();
+            assert_eq!((parsed.date(), parsed.offset()), (date, offset));
+        }
+        assert_eq!(HTDateTimeOffset::new(date, HTOffset::from_sks(5).unwrap()).to_string(), "2019-GA-01T31S2000R+05S0000R");
+        assert_eq!(HTDateTimeOffset::interpret_string("2019-GA-01T31S2000R"), Err(HTParseError::MalformedString));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{month_from_index, month_index, Month, MonthStatus, MONTHS_PER_YEAR};
+
+// the single definition of how months and statuses are ordered, everything that needs a month's
+// position (ordering, arithmetic, sortable keys) goes through here
+
+// the matches are exhaustive on purpose, a month added to ht_cal stops this crate compiling
+// until it's been given a place instead of silently sorting wrong
+pub const fn month_number(month: Month) -> u8 {
+    match month {
+        Month::Zero => 0,
+        Month::Niktvirin => 1,
+        Month::Apress => 2,
+        Month::Smosh => 3,
+        Month::Funny => 4,
+    }
+}
+
+pub const fn status_number(status: MonthStatus) -> u8 {
+    match status {
+        MonthStatus::Greater => 0,
+        MonthStatus::Lesser => 1,
+    }
+}
+
+pub trait MonthExt: Sized {
+    const ORDER: [Month; 5];
+    fn index(self) -> u8;
+    fn from_index(index: u8) -> Option<Self>;
+}
+
+pub trait MonthStatusExt: Sized {
+    const ORDER: [MonthStatus; 2];
+    fn index(self) -> u8;
+    fn from_index(index: u8) -> Option<Self>;
+}
+
+impl MonthExt for Month {
+    const ORDER: [Month; 5] = [Month::Zero, Month::Niktvirin, Month::Apress, Month::Smosh, Month::Funny];
+
+    fn index(self) -> u8 {
+        month_number(self)
+    }
+
+    fn from_index(index: u8) -> Option<Self> {
+        <Month as MonthExt>::ORDER.get(index as usize).copied()
+    }
+}
+
+impl MonthStatusExt for MonthStatus {
+    const ORDER: [MonthStatus; 2] = [MonthStatus::Greater, MonthStatus::Lesser];
+
+    fn index(self) -> u8 {
+        status_number(self)
+    }
+
+    fn from_index(index: u8) -> Option<Self> {
+        <MonthStatus as MonthStatusExt>::ORDER.get(index as usize).copied()
+    }
+}
+
+// a month of the year as a (status, month) pair, in MONTH_ORDER. next and prev wrap around the
+// end of the year, so the year has to be carried by the caller
+pub trait MonthPairExt: Sized {
+    fn index_in_year(self) -> u8;
+    fn from_index(index: u8) -> Option<Self>;
+    fn next(self) -> Self;
+    fn prev(self) -> Self;
+}
+
+impl MonthPairExt for (MonthStatus, Month) {
+    fn index_in_year(self) -> u8 {
+        month_index(self)
+    }
+
+    fn from_index(index: u8) -> Option<Self> {
+        month_from_index(index)
+    }
+
+    fn next(self) -> Self {
+        // the index is always in range
+        month_from_index((month_index(self) + 1) % MONTHS_PER_YEAR).unwrap_or(self)
+    }
+
+    fn prev(self) -> Self {
+        month_from_index((month_index(self) + MONTHS_PER_YEAR - 1) % MONTHS_PER_YEAR).unwrap_or(self)
+    }
+}
+
+// checked at compile time: the arrays list every value in numbering order
+const _: () = {
+    let months = <Month as MonthExt>::ORDER;
+    let mut i = 0;
+    while i < months.len() {
+        assert!(month_number(months[i]) as usize == i);
+        i += 1;
+    }
+    let statuses = <MonthStatus as MonthStatusExt>::ORDER;
+    let mut i = 0;
+    while i < statuses.len() {
+        assert!(status_number(statuses[i]) as usize == i);
+        i += 1;
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MONTH_ORDER;
+
+    #[test]
+    fn steps_through_month_pairs() {
+        for (index, month) in MONTH_ORDER.iter().enumerate() {
+            assert_eq!(month.index_in_year() as usize, index);
+            assert_eq!(<(MonthStatus, Month) as MonthPairExt>::from_index(index as u8), Some(*month));
+            assert_eq!(month.next().prev(), *month);
+        }
+        assert_eq!((MonthStatus::Greater, Month::Funny).next(), (MonthStatus::Lesser, Month::Zero));
+        assert_eq!((MonthStatus::Lesser, Month::Funny).next(), (MonthStatus::Greater, Month::Zero));
+        assert_eq!((MonthStatus::Greater, Month::Zero).prev(), (MonthStatus::Lesser, Month::Funny));
+        assert_eq!(<(MonthStatus, Month) as MonthPairExt>::from_index(10), None);
+    }
+}
@@ -0,0 +1,208 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::{days_in_month, month_index, month_letter, parse_month_from_gl_and_m, status_letter, HTDate, HTParseError, Month, MonthStatus};
+
+// a month of a particular year, written 2019-GA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTYearMonth {
+    pub year: u128,
+    pub month: (MonthStatus, Month),
+}
+
+impl HTYearMonth {
+    pub fn new(year: u128, month_status: MonthStatus, month: Month) -> Self {
+        HTYearMonth {
+            year,
+            month: (month_status, month),
+        }
+    }
+
+    pub fn length(&self) -> u8 {
+        days_in_month(self.year, self.month.0, self.month.1)
+    }
+
+    pub fn at_day(&self, day: u8) -> Result<HTDate, HTParseError> {
+        if day == 0 {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        if day > self.length() {
+            return Err(HTParseError::TooManyDays);
+        }
+        Ok(HTDate::new(self.year, self.month.0, self.month.1, day, 0))
+    }
+
+    // the start of each day of the month in order
+    pub fn days(&self) -> impl Iterator<Item = HTDate> {
+        let month = *self;
+        (1..=self.length()).map(move |day| HTDate::new(month.year, month.month.0, month.month.1, day, 0))
+    }
+
+    // 2019-GA or 2019GA
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        if !input.is_ascii() || input.len() < 3 {
+            return Err(HTParseError::MalformedString);
+        }
+        let (year, letters) = input.split_at(input.len() - 2);
+        let year = year.strip_suffix('-').unwrap_or(year);
+        if year.is_empty() || !year.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(HTParseError::MalformedString);
+        }
+        let (month_status, month) = parse_month_from_gl_and_m(&letters[..1], &letters[1..])?;
+        Ok(HTYearMonth::new(year.parse().map_err(|_| HTParseError::MalformedString)?, month_status, month))
+    }
+}
+
+impl PartialOrd for HTYearMonth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HTYearMonth {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, month_index(self.month)).cmp(&(other.year, month_index(other.month)))
+    }
+}
+
+impl Display for HTYearMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}-{}{}", self.year, status_letter(self.month.0), month_letter(self.month.1))
+    }
+}
+
+impl FromStr for HTYearMonth {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HTYearMonth::interpret_string(s)
+    }
+}
+
+// a day of the year without the year, for anniversaries. written --GA-05
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTMonthDay {
+    month: (MonthStatus, Month),
+    day: u8,
+}
+
+impl HTMonthDay {
+    // the day has to exist in the month, in some year at least
+    pub fn new(month_status: MonthStatus, month: Month, day: u8) -> Result<Self, HTParseError> {
+        if day == 0 {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        if day > days_in_month(0, month_status, month) {
+            return Err(HTParseError::TooManyDays);
+        }
+        Ok(HTMonthDay {
+            month: (month_status, month),
+            day,
+        })
+    }
+
+    pub fn month(&self) -> (MonthStatus, Month) {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn in_year(&self, year: u128) -> Result<HTDate, HTParseError> {
+        HTYearMonth::new(year, self.month.0, self.month.1).at_day(self.day)
+    }
+
+    // the start of the first day strictly after date's day that falls on this month and day.
+    // None past the last year
+    pub fn next_occurrence_after(&self, date: &HTDate) -> Option<HTDate> {
+        let this_year = (month_index(self.month), self.day) > (month_index(date.month), date.day);
+        let year = if this_year { date.year } else { date.year.checked_add(1)? };
+        self.in_year(year).ok()
+    }
+
+    // --GA-05
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        let rest = input.strip_prefix("--").ok_or(HTParseError::MalformedString)?;
+        if rest.len() != 5 || !rest.is_ascii() || &rest[2..3] != "-" || !rest[3..].bytes().all(|c| c.is_ascii_digit()) {
+            return Err(HTParseError::MalformedString);
+        }
+        let (month_status, month) = parse_month_from_gl_and_m(&rest[..1], &rest[1..2])?;
+        HTMonthDay::new(month_status, month, rest[3..].parse().map_err(|_| HTParseError::MalformedString)?)
+    }
+}
+
+impl PartialOrd for HTMonthDay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HTMonthDay {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (month_index(self.month), self.day).cmp(&(month_index(other.month), other.day))
+    }
+}
+
+impl Display for HTMonthDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "--{}{}-{:02}", status_letter(self.month.0), month_letter(self.month.1), self.day)
+    }
+}
+
+impl FromStr for HTMonthDay {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HTMonthDay::interpret_string(s)
+    }
+}
+
+impl HTDate {
+    pub fn year_month(&self) -> HTYearMonth {
+        HTYearMonth::new(self.year, self.month.0, self.month.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_writes_year_months() {
+        let month = HTYearMonth::new(2019, MonthStatus::Greater, Month::Apress);
+        assert_eq!(month.to_string(), "2019-GA");
+        assert_eq!("2019-GA".parse(), Ok(month));
+        assert_eq!("2019GA".parse(), Ok(month));
+        assert_eq!("19-GA".parse::<HTYearMonth>().map(|m| m.year), Ok(19));
+        assert_eq!("2019-GX".parse::<HTYearMonth>(), Err(HTParseError::MalformedString));
+        assert_eq!("-GA".parse::<HTYearMonth>(), Err(HTParseError::MalformedString));
+        assert!(month < HTYearMonth::new(2019, MonthStatus::Lesser, Month::Zero));
+        assert!(HTYearMonth::new(2018, MonthStatus::Lesser, Month::Funny) < month);
+    }
+
+    #[test]
+    fn lists_the_days_of_a_month() {
+        let month = HTYearMonth::new(2019, MonthStatus::Lesser, Month::Smosh);
+        let days: Vec<HTDate> = month.days().collect();
+        assert_eq!(days.len(), 24);
+        assert_eq!(days[0], HTDate::new(2019, MonthStatus::Lesser, Month::Smosh, 1, 0));
+        assert!(days.iter().all(|day| day.year_month() == month));
+        assert_eq!(month.at_day(25), Err(HTParseError::TooManyDays));
+    }
+
+    #[test]
+    fn finds_the_next_anniversary() {
+        let anniversary = HTMonthDay::new(MonthStatus::Greater, Month::Smosh, 5).unwrap();
+        assert_eq!(anniversary.to_string(), "--GS-05");
+        assert_eq!("--GS-05".parse(), Ok(anniversary));
+        let before = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 20, 100);
+        assert_eq!(anniversary.next_occurrence_after(&before), Some(HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 5, 0)));
+        let on_the_day = HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 5, 100);
+        assert_eq!(anniversary.next_occurrence_after(&on_the_day), Some(HTDate::new(2020, MonthStatus::Greater, Month::Smosh, 5, 0)));
+        assert_eq!(HTMonthDay::new(MonthStatus::Lesser, Month::Zero, 25), Err(HTParseError::TooManyDays));
+        assert_eq!("--GS-5".parse::<HTMonthDay>(), Err(HTParseError::MalformedString));
+        assert!(anniversary < HTMonthDay::new(MonthStatus::Lesser, Month::Zero, 1).unwrap());
+    }
+}
@@ -0,0 +1,166 @@
+use core::fmt::{Display, Formatter};
+use core::ops::Add;
+
+use crate::{month_index, DayPolicy, HTDate, MONTHS_PER_YEAR};
+
+// years, months and days counted on the calendar, unlike HTDuration's fixed ticks. adding one
+// month to the 3rd gives the 3rd of the next month whatever the months' lengths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HTPeriod {
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+}
+
+impl HTPeriod {
+    pub const ZERO: HTPeriod = HTPeriod { years: 0, months: 0, days: 0 };
+
+    pub fn new(years: i64, months: i64, days: i64) -> Self {
+        HTPeriod { years, months, days }
+    }
+
+    pub fn of_years(years: i64) -> Self {
+        HTPeriod::new(years, 0, 0)
+    }
+
+    pub fn of_months(months: i64) -> Self {
+        HTPeriod::new(0, months, 0)
+    }
+
+    pub fn of_days(days: i64) -> Self {
+        HTPeriod::new(0, 0, days)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == HTPeriod::ZERO
+    }
+
+    // years and months together, as a number of months
+    pub fn total_months(&self) -> i128 {
+        self.years as i128 * MONTHS_PER_YEAR as i128 + self.months as i128
+    }
+
+    // months folded into years so there are fewer than a year's worth, with the same sign as the
+    // total. days are left alone since how many make a month depends on the month
+    pub fn normalized(&self) -> Option<Self> {
+        let total = self.total_months();
+        Some(HTPeriod {
+            years: i64::try_from(total / MONTHS_PER_YEAR as i128).ok()?,
+            months: (total % MONTHS_PER_YEAR as i128) as i64,
+            days: self.days,
+        })
+    }
+
+    pub fn checked_add(&self, other: HTPeriod) -> Option<Self> {
+        Some(HTPeriod {
+            years: self.years.checked_add(other.years)?,
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+        })
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(HTPeriod {
+            years: self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+        })
+    }
+
+    // the normalized period with start + period landing on end's day, ignoring the time of day.
+    // the months and days have the same sign, negative when end is before start
+    pub fn between(start: &HTDate, end: &HTDate) -> Option<Self> {
+        let months_of = |date: &HTDate| date.year.checked_mul(MONTHS_PER_YEAR as u128).map(|months| months + month_index(date.month) as u128);
+        let mut total = months_of(end)? as i128 - months_of(start)? as i128;
+        let day_difference = end.day as i128 - start.day as i128;
+        if total > 0 && day_difference < 0 {
+            total -= 1;
+        } else if total < 0 && day_difference > 0 {
+            total += 1;
+        }
+        let stepped = start.add_months(i64::try_from(total).ok()?, DayPolicy::Clamp)?;
+        let days = end.to_day_number()? - stepped.to_day_number()?;
+        Some(HTPeriod {
+            years: i64::try_from(total / MONTHS_PER_YEAR as i128).ok()?,
+            months: (total % MONTHS_PER_YEAR as i128) as i64,
+            days: i64::try_from(days).ok()?,
+        })
+    }
+}
+
+impl HTDate {
+    // years and months first, with policy deciding what happens to a day the new month doesn't
+    // have, then the days
+    pub fn checked_add_period(&self, period: HTPeriod, policy: DayPolicy) -> Option<Self> {
+        let moved = self.add_months(i64::try_from(period.total_months()).ok()?, policy)?;
+        if period.days < 0 {
+            moved.checked_sub_days(period.days.unsigned_abs() as u128)
+        } else {
+            moved.checked_add_days(period.days as u128)
+        }
+    }
+}
+
+// date + period clamps the day like DayPolicy::Clamp and panics where checked_add_period gives None
+impl Add<HTPeriod> for HTDate {
+    type Output = HTDate;
+
+    fn add(self, period: HTPeriod) -> HTDate {
+        self.checked_add_period(period, DayPolicy::Clamp).expect("date out of range after adding period")
+    }
+}
+
+// P1Y2M3D, with a minus on each negative part
+impl Display for HTPeriod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "P{}Y{}M{}D", self.years, self.months, self.days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    fn date(year: u128, status: MonthStatus, month: Month, day: u8) -> HTDate {
+        HTDate::new(year, status, month, day, 100)
+    }
+
+    #[test]
+    fn adds_on_the_calendar() {
+        let start = date(2019, MonthStatus::Greater, Month::Funny, 3);
+        assert_eq!(start + HTPeriod::of_months(1), date(2019, MonthStatus::Lesser, Month::Zero, 3));
+        assert_eq!(start + HTPeriod::new(1, -1, 2), date(2020, MonthStatus::Greater, Month::Smosh, 5));
+        assert_eq!(start + HTPeriod::of_days(-3), date(2019, MonthStatus::Greater, Month::Smosh, 24));
+        let first = date(0, MonthStatus::Greater, Month::Zero, 1);
+        assert_eq!(first.checked_add_period(HTPeriod::of_days(-1), DayPolicy::Clamp), None);
+    }
+
+    #[test]
+    fn normalizes_months_into_years() {
+        assert_eq!(HTPeriod::new(1, 13, 30).normalized(), Some(HTPeriod::new(2, 3, 30)));
+        assert_eq!(HTPeriod::new(1, -13, 0).normalized(), Some(HTPeriod::new(0, -3, 0)));
+        assert_eq!(HTPeriod::new(i64::MAX, 10, 0).normalized(), None);
+        assert_eq!(HTPeriod::new(1, 2, 3).to_string(), "P1Y2M3D");
+        assert!(HTPeriod::default().is_zero());
+    }
+
+    #[test]
+    fn finds_the_period_between_dates() {
+        let start = date(2019, MonthStatus::Greater, Month::Apress, 20);
+        let ends = [
+            date(2019, MonthStatus::Greater, Month::Apress, 20),
+            date(2019, MonthStatus::Greater, Month::Smosh, 3),
+            date(2021, MonthStatus::Lesser, Month::Niktvirin, 22),
+            date(2018, MonthStatus::Lesser, Month::Funny, 21),
+            date(2019, MonthStatus::Greater, Month::Zero, 2),
+        ];
+        for end in ends {
+            let period = HTPeriod::between(&start, &end).unwrap();
+            assert_eq!(start + period, end);
+            assert!(period.months.signum() * period.days.signum() >= 0);
+        }
+        assert_eq!(HTPeriod::between(&start, &ends[1]), Some(HTPeriod::new(0, 0, 7)));
+        assert_eq!(HTPeriod::between(&start, &ends[2]), Some(HTPeriod::new(2, 4, 2)));
+    }
+}
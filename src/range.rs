@@ -0,0 +1,104 @@
+use crate::duration::HTDuration;
+use crate::{HTDate, HTParseError, SECONDS_PER_DAY};
+
+// the dates from start up to but not including end, a day apart unless step_by_duration says
+// otherwise. an end at or before the start gives nothing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HTDateRange {
+    // epoch ticks, front is the next date forwards and back is one past where backwards starts
+    front: u128,
+    back: u128,
+    step: u128,
+}
+
+impl HTDateRange {
+    // Err for dates too far out to count in ticks
+    pub fn new(start: HTDate, end: HTDate) -> Result<Self, HTParseError> {
+        let front = start.checked_epoch().ok_or(HTParseError::OtherwiseInvalidDate)?;
+        let back = end.checked_epoch().ok_or(HTParseError::OtherwiseInvalidDate)?;
+        Ok(HTDateRange {
+            front,
+            back: back.max(front),
+            step: SECONDS_PER_DAY,
+        })
+    }
+
+    // panics unless step is positive, like Iterator::step_by(0)
+    pub fn step_by_duration(self, step: HTDuration) -> Self {
+        assert!(step.ticks() > 0, "date range step must be positive");
+        HTDateRange {
+            step: step.ticks() as u128,
+            ..self
+        }
+    }
+
+    fn remaining(&self) -> u128 {
+        (self.back - self.front).div_ceil(self.step)
+    }
+}
+
+impl Iterator for HTDateRange {
+    type Item = HTDate;
+
+    fn next(&mut self) -> Option<HTDate> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = HTDate::from_epoch(self.front);
+        self.front = self.front.checked_add(self.step).unwrap_or(self.back).min(self.back);
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (usize::try_from(remaining).unwrap_or(usize::MAX), usize::try_from(remaining).ok())
+    }
+}
+
+// backwards from the last date a whole number of steps after the start
+impl DoubleEndedIterator for HTDateRange {
+    fn next_back(&mut self) -> Option<HTDate> {
+        if self.front >= self.back {
+            return None;
+        }
+        let last = self.front + (self.back - 1 - self.front) / self.step * self.step;
+        self.back = last;
+        Some(HTDate::from_epoch(last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    fn date(day: u8, second: u128) -> HTDate {
+        HTDate::new(2019, MonthStatus::Greater, Month::Apress, day, second)
+    }
+
+    #[test]
+    fn steps_by_day() {
+        let days: Vec<HTDate> = HTDateRange::new(date(22, 100), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 2, 100)).unwrap().collect();
+        assert_eq!(days, vec![date(22, 100), date(23, 100), date(24, 100), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 1, 100)]);
+        assert_eq!(HTDateRange::new(date(5, 0), date(5, 0)).unwrap().count(), 0);
+        assert_eq!(HTDateRange::new(date(5, 0), date(1, 0)).unwrap().count(), 0);
+        assert_eq!(HTDateRange::new(date(1, 0), date(3, 1)).unwrap().size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn steps_by_duration_from_both_ends() {
+        let range = HTDateRange::new(date(1, 0), date(1, 10)).unwrap().step_by_duration(HTDuration::from_ticks(3));
+        assert_eq!(range.clone().map(|d| d.second).collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+        assert_eq!(range.clone().rev().map(|d| d.second).collect::<Vec<_>>(), vec![9, 6, 3, 0]);
+        let mut both = range;
+        assert_eq!(both.next().map(|d| d.second), Some(0));
+        assert_eq!(both.next_back().map(|d| d.second), Some(9));
+        assert_eq!(both.map(|d| d.second).collect::<Vec<_>>(), vec![3, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_zero_steps() {
+        let _ = HTDateRange::new(date(1, 0), date(2, 0)).unwrap().step_by_duration(HTDuration::ZERO);
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::string::{String, ToString};
+use crate::breakdown::calendar_breakdown;
+use crate::locale::HTLocale;
+use crate::{Granularity, HTDate};
+
+// "3 days ago", "in 2 sks" or "just now", using the largest unit that isn't zero. units finer
+// than the granularity are never shown, so anything closer than one of them is "just now".
+// None if either date is too far out to compare
+pub fn format_relative(date: &HTDate, now: &HTDate, granularity: Granularity) -> Option<String> {
+    format_relative_localized(date, now, granularity, &HTLocale::ENGLISH)
+}
+
+pub fn format_relative_localized(date: &HTDate, now: &HTDate, granularity: Granularity, locale: &HTLocale) -> Option<String> {
+    let breakdown = calendar_breakdown(now, date)?;
+    let units = [
+        (Granularity::Year, breakdown.years),
+        (Granularity::Month, breakdown.months as u128),
+        (Granularity::Day, breakdown.days as u128),
+        (Granularity::Sks, breakdown.sks),
+        (Granularity::Remainder, breakdown.rem),
+    ];
+    let largest = units.iter().filter(|(unit, _)| *unit <= granularity).find(|(_, amount)| *amount > 0);
+    Some(match largest {
+        None => locale.now.to_string(),
+        Some((unit, amount)) => {
+            let phrase = if breakdown.negative { locale.past } else { locale.future };
+            phrase.replace("{}", &locale.amount(*amount, *unit))
+        }
+    })
+}
+
+// how close a distance has to come to a unit to be counted in it, as a percentage of the unit
+// and coarsest unit first like Granularity. the default has 9 months as "about 1 year" and
+// 75 sks as "about 1 day", and anything under a sks as "less than a sks"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HTDistanceThresholds {
+    pub percent: [u32; 4],
+}
+
+impl Default for HTDistanceThresholds {
+    fn default() -> Self {
+        HTDistanceThresholds { percent: [90, 75, 75, 100] }
+    }
+}
+
+impl HTDate {
+    // "about 3 months", "2 days" or "less than a sks" between the two dates in either order,
+    // rounded to the nearest whole unit. None if either date is too far out to compare
+    pub fn distance_in_words(&self, other: &HTDate, locale: &HTLocale) -> Option<String> {
+        self.distance_in_words_with(other, locale, &HTDistanceThresholds::default())
+    }
+
+    pub fn distance_in_words_with(&self, other: &HTDate, locale: &HTLocale, thresholds: &HTDistanceThresholds) -> Option<String> {
+        let ticks = calendar_breakdown(self, other)?.total_ticks;
+        let units = [Granularity::Year, Granularity::Month, Granularity::Day, Granularity::Sks];
+        let unit = units.iter().find(|unit| ticks.saturating_mul(100) >= unit.ticks().saturating_mul(thresholds.percent[**unit as usize] as u128));
+        Some(match unit {
+            None => locale.less_than.replace("{}", locale.unit_names[Granularity::Sks as usize].0),
+            Some(unit) => {
+                let amount = (ticks / unit.ticks() + (ticks % unit.ticks() >= unit.ticks().div_ceil(2)) as u128).max(1);
+                let words = locale.amount(amount, *unit);
+                if ticks % unit.ticks() == 0 {
+                    words
+                } else {
+                    locale.about.replace("{}", &words)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn formats_relative_times() {
+        let now = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 50 * 6000);
+        let earlier = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 7, 50 * 6000);
+        let later = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 52 * 6000 + 10);
+        assert_eq!(format_relative(&earlier, &now, Granularity::Sks).unwrap(), "3 days ago");
+        assert_eq!(format_relative(&later, &now, Granularity::Sks).unwrap(), "in 2 sks");
+        assert_eq!(format_relative(&later, &now, Granularity::Day).unwrap(), "just now");
+        assert_eq!(format_relative(&now, &now, Granularity::Remainder).unwrap(), "just now");
+        let next_year = HTDate::new(2020, MonthStatus::Greater, Month::Apress, 10, 50 * 6000);
+        assert_eq!(format_relative(&next_year, &now, Granularity::Remainder).unwrap(), "in 1 year");
+    }
+
+    #[test]
+    fn formats_relative_times_localized() {
+        let locale = HTLocale {
+            unit_names: [("an", "ans"), ("mois", "mois"), ("jour", "jours"), ("sks", "sks"), ("rem", "rem")],
+            past: "il y a {}",
+            future: "dans {}",
+            now: "maintenant",
+            ..HTLocale::ENGLISH
+        };
+        let now = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 0);
+        let earlier = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 7, 0);
+        assert_eq!(format_relative_localized(&earlier, &now, Granularity::Sks, &locale).unwrap(), "il y a 3 jours");
+        assert_eq!(format_relative_localized(&now, &earlier, Granularity::Sks, &locale).unwrap(), "dans 3 jours");
+        assert_eq!(format_relative_localized(&now, &now, Granularity::Sks, &locale).unwrap(), "maintenant");
+    }
+
+    #[test]
+    fn describes_distances_in_words() {
+        let now = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 50 * 6000);
+        let words = |date: HTDate| now.distance_in_words(&date, &HTLocale::ENGLISH).unwrap();
+        assert_eq!(words(now), "less than a sks");
+        assert_eq!(words(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 50 * 6000 + 5999)), "less than a sks");
+        assert_eq!(words(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 10, 53 * 6000)), "3 sks");
+        assert_eq!(words(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 11, 30 * 6000)), "about 1 day");
+        assert_eq!(words(HTDate::new(2019, MonthStatus::Greater, Month::Apress, 7, 50 * 6000)), "3 days");
+        assert_eq!(words(HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0)), "about 3 months");
+        assert_eq!(words(HTDate::new(2018, MonthStatus::Lesser, Month::Zero, 20, 0)), "about 7 months");
+        assert_eq!(words(HTDate::new(2018, MonthStatus::Greater, Month::Smosh, 10, 0)), "about 1 year");
+        let strict = HTDistanceThresholds { percent: [100, 100, 100, 100] };
+        let date = HTDate::new(2018, MonthStatus::Greater, Month::Smosh, 10, 0);
+        assert_eq!(now.distance_in_words_with(&date, &HTLocale::ENGLISH, &strict).unwrap(), "about 9 months");
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{month_from_index, month_index, HTDate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HTSeason {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+// the one place months are split into seasons: each season with the index of its first month.
+// ten months don't split evenly, so spring and autumn get three and the others two. a season
+// runs up to the next one's first month and winter to the end of the year
+pub const SEASON_STARTS: [(HTSeason, u8); 4] = [(HTSeason::Spring, 0), (HTSeason::Summer, 3), (HTSeason::Autumn, 5), (HTSeason::Winter, 8)];
+
+impl HTDate {
+    pub fn season(&self) -> HTSeason {
+        SEASON_STARTS[self.season_position()].0
+    }
+
+    // 1 to 4, the season's position in the year. reporting quarters follow the seasons
+    pub fn quarter(&self) -> u8 {
+        self.season_position() as u8 + 1
+    }
+
+    // the first day of the season's first month, at the start of the day
+    pub fn start_of_season(&self) -> HTDate {
+        let first = SEASON_STARTS[self.season_position()].1;
+        // every index in SEASON_STARTS is a month
+        let (month_status, month) = month_from_index(first).unwrap_or(self.month);
+        HTDate::new(self.year, month_status, month, 1, 0)
+    }
+
+    fn season_position(&self) -> usize {
+        let index = month_index(self.month);
+        SEASON_STARTS.iter().rposition(|(_, first)| *first <= index).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus, MONTH_ORDER};
+
+    #[test]
+    fn maps_months_to_seasons() {
+        let seasons: Vec<HTSeason> = MONTH_ORDER.iter().map(|(status, month)| HTDate::new(2019, *status, *month, 1, 0).season()).collect();
+        use HTSeason::*;
+        assert_eq!(seasons, vec![Spring, Spring, Spring, Summer, Summer, Autumn, Autumn, Autumn, Winter, Winter]);
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 12, 100);
+        assert_eq!(date.quarter(), 3);
+        assert_eq!(date.start_of_season(), HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 1, 0));
+        assert_eq!(HTDate::new(2019, MonthStatus::Greater, Month::Zero, 1, 0).quarter(), 1);
+    }
+}
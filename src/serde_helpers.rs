@@ -0,0 +1,403 @@
+// HTDate's own Serialize and Deserialize, and modules for #[serde(with = "...")] on HTDate fields
+use crate::{month_from_index, month_index, HTDate};
+use alloc::format;
+use core::fmt::Formatter;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// the canonical string for human readable formats like json and yaml, and a (year, month index,
+// day, second) tuple for binary ones like bincode and postcard
+impl Serialize for HTDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        let mut tuple = serializer.serialize_tuple(4)?;
+        tuple.serialize_element(&self.year)?;
+        tuple.serialize_element(&month_index(self.month))?;
+        tuple.serialize_element(&self.day)?;
+        tuple.serialize_element(&self.second)?;
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for HTDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return date_or_datetime::deserialize(deserializer);
+        }
+
+        struct TupleVisitor;
+
+        impl<'de> Visitor<'de> for TupleVisitor {
+            type Value = HTDate;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.write_str("an HT date as (year, month index, day, second)")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<HTDate, A::Error> {
+                let year: u128 = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let index: u8 = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                let day: u8 = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(2, &self))?;
+                let second: u128 = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(3, &self))?;
+                let (status, month) = month_from_index(index).ok_or_else(|| A::Error::custom("month index out of range"))?;
+                HTDate::new_checked(year, status, month, day, second).map_err(|e| A::Error::custom(format!("invalid HT date: {:?}", e)))
+            }
+        }
+
+        deserializer.deserialize_tuple(4, TupleVisitor)
+    }
+}
+
+// reads "2019-GA-01" as well as "2019-GA-01T31S2000R", the date-only form meaning the start of the
+// day, and always writes the full canonical string:
+//
+// #[serde(with = "ht_timeparser::serde_helpers::date_or_datetime")]
+// expires: HTDate,
+pub mod date_or_datetime {
+    use crate::HTDate;
+    use serde::de::{Error, Visitor};
+    use alloc::format;
+    use serde::{Deserializer, Serializer};
+    use core::fmt::Formatter;
+
+    pub fn serialize<S: Serializer>(date: &HTDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(date)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HTDate, D::Error> {
+        struct DateVisitor;
+
+        impl Visitor<'_> for DateVisitor {
+            type Value = HTDate;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.write_str("an HT date like 2019-GA-01 or 2019-GA-01T31S2000R")
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<HTDate, E> {
+                HTDate::interpret_string(value).map_err(|e| E::custom(format!("invalid HT date {:?}: {:?}", value, e)))
+            }
+        }
+
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+// the compact form, "2019GA01T31S2000R". reads anything interpret_string does
+pub mod compact_string {
+    use crate::HTDate;
+    use alloc::{format, string::String};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(date: &HTDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&date.to_compact_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HTDate, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        HTDate::interpret_string(&value).map_err(|e| D::Error::custom(format!("invalid HT date {:?}: {:?}", value, e)))
+    }
+}
+
+// ticks since the epoch as a single integer, see HTDate::checked_epoch. formats without u128
+// support can't hold it
+pub mod epoch_ticks {
+    use crate::HTDate;
+    use serde::ser::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(date: &HTDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let ticks = date.checked_epoch().ok_or_else(|| S::Error::custom("HT date too far from the epoch for a tick count"))?;
+        serializer.serialize_u128(ticks)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HTDate, D::Error> {
+        u128::deserialize(deserializer).map(HTDate::from_epoch)
+    }
+}
+
+// a struct of the parts, the same shape as HTDate::to_json_value:
+// {"year": 2019, "monthStatus": "Greater", "month": "Apress", "day": 1, "sks": 31, "rem": 2000}
+pub mod components {
+    use crate::order::{MonthExt, MonthStatusExt};
+    use crate::{month_name, status_name, HTDate, Month, MonthStatus, SECONDS_PER_SKS};
+    use alloc::{format, string::String};
+    use core::fmt::Formatter;
+    use serde::de::{Error, MapAccess, Visitor};
+    use serde::ser::SerializeStruct;
+    use serde::{Deserializer, Serializer};
+
+    const FIELDS: &[&str] = &["year", "monthStatus", "month", "day", "sks", "rem"];
+
+    pub fn serialize<S: Serializer>(date: &HTDate, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut parts = serializer.serialize_struct("HTDate", FIELDS.len())?;
+        parts.serialize_field("year", &date.year)?;
+        parts.serialize_field("monthStatus", status_name(date.month.0))?;
+        parts.serialize_field("month", month_name(date.month.1))?;
+        parts.serialize_field("day", &date.day)?;
+        parts.serialize_field("sks", &(date.second / SECONDS_PER_SKS))?;
+        parts.serialize_field("rem", &((date.second % SECONDS_PER_SKS) as u16))?;
+        parts.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HTDate, D::Error> {
+        struct ComponentsVisitor;
+
+        impl<'de> Visitor<'de> for ComponentsVisitor {
+            type Value = HTDate;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.write_str("an HT date as year, monthStatus, month, day, sks and rem")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<HTDate, A::Error> {
+                let (mut year, mut status, mut month, mut day, mut sks, mut rem) = (None, None, None, None, None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "year" => year = Some(map.next_value::<u128>()?),
+                        "monthStatus" => {
+                            let name = map.next_value::<String>()?;
+                            let found = MonthStatus::ORDER.into_iter().find(|s| status_name(*s) == name);
+                            status = Some(found.ok_or_else(|| A::Error::unknown_variant(&name, &["Greater", "Lesser"]))?);
+                        }
+                        "month" => {
+                            let name = map.next_value::<String>()?;
+                            let found = Month::ORDER.into_iter().find(|m| month_name(*m) == name);
+                            month = Some(found.ok_or_else(|| A::Error::custom("unknown month name"))?);
+                        }
+                        "day" => day = Some(map.next_value::<u8>()?),
+                        "sks" => sks = Some(map.next_value::<u128>()?),
+                        "rem" => rem = Some(map.next_value::<u128>()?),
+                        other => return Err(A::Error::unknown_field(other, FIELDS)),
+                    }
+                }
+                let year = year.ok_or_else(|| A::Error::missing_field("year"))?;
+                let status = status.ok_or_else(|| A::Error::missing_field("monthStatus"))?;
+                let month = month.ok_or_else(|| A::Error::missing_field("month"))?;
+                let day = day.ok_or_else(|| A::Error::missing_field("day"))?;
+                let sks = sks.ok_or_else(|| A::Error::missing_field("sks"))?;
+                let rem = rem.ok_or_else(|| A::Error::missing_field("rem"))?;
+                if rem >= SECONDS_PER_SKS {
+                    return Err(A::Error::custom("rem past the end of the sks"));
+                }
+                let second = sks.checked_mul(SECONDS_PER_SKS).ok_or_else(|| A::Error::custom("sks out of range"))? + rem;
+                HTDate::new_checked(year, status, month, day, second).map_err(|e| A::Error::custom(format!("invalid HT date: {:?}", e)))
+            }
+        }
+
+        deserializer.deserialize_struct("HTDate", FIELDS, ComponentsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HTDate, Month, MonthStatus};
+    use serde::de::value::Error;
+    use serde::de::{DeserializeSeed, IntoDeserializer, SeqAccess};
+    use serde::ser::{Error as _, Impossible, SerializeTuple};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    // a stand-in for bincode and friends: not human readable, values are a flat list of integers
+    struct Integers<'a>(&'a mut Vec<u128>);
+
+    macro_rules! unsupported {
+        ($($name:ident($($arg:ty),*) -> $ok:ty;)*) => {
+            $(fn $name(self, $(_: $arg),*) -> Result<$ok, Error> {
+                Err(Error::custom("only integers and tuples"))
+            })*
+        };
+    }
+
+    impl<'a> Serializer for Integers<'a> {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Impossible<(), Error>;
+        type SerializeTuple = Self;
+        type SerializeTupleStruct = Impossible<(), Error>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeStruct = Impossible<(), Error>;
+        type SerializeStructVariant = Impossible<(), Error>;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.serialize_u128(v as u128)
+        }
+
+        fn serialize_u128(self, v: u128) -> Result<(), Error> {
+            self.0.push(v);
+            Ok(())
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self, Error> {
+            Ok(self)
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), Error> {
+            Err(Error::custom("only integers and tuples"))
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _: &'static str, _: &T) -> Result<(), Error> {
+            Err(Error::custom("only integers and tuples"))
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<(), Error> {
+            Err(Error::custom("only integers and tuples"))
+        }
+
+        unsupported! {
+            serialize_bool(bool) -> ();
+            serialize_i8(i8) -> ();
+            serialize_i16(i16) -> ();
+            serialize_i32(i32) -> ();
+            serialize_i64(i64) -> ();
+            serialize_u16(u16) -> ();
+            serialize_u32(u32) -> ();
+            serialize_u64(u64) -> ();
+            serialize_f32(f32) -> ();
+            serialize_f64(f64) -> ();
+            serialize_char(char) -> ();
+            serialize_str(&str) -> ();
+            serialize_bytes(&[u8]) -> ();
+            serialize_none() -> ();
+            serialize_unit() -> ();
+            serialize_unit_struct(&'static str) -> ();
+            serialize_unit_variant(&'static str, u32, &'static str) -> ();
+            serialize_seq(Option<usize>) -> Self::SerializeSeq;
+            serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+            serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+            serialize_map(Option<usize>) -> Self::SerializeMap;
+            serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+            serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+        }
+    }
+
+    impl SerializeTuple for Integers<'_> {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(Integers(&mut *self.0))
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    struct FromIntegers<'a>(core::slice::Iter<'a, u128>);
+
+    impl<'de> Deserializer<'de> for FromIntegers<'_> {
+        type Error = Error;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_seq(self)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+            unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    impl<'de> SeqAccess<'de> for FromIntegers<'_> {
+        type Error = Error;
+
+        fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+            // the primitive visitors only take u128 for u128 itself
+            self.0
+                .next()
+                .map(|&v| match u64::try_from(v) {
+                    Ok(small) => seed.deserialize(small.into_deserializer()),
+                    Err(_) => seed.deserialize(v.into_deserializer()),
+                })
+                .transpose()
+        }
+    }
+
+    #[test]
+    fn switches_on_human_readable() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""2019-LA-05T31S2000R""#);
+        assert_eq!(serde_json::from_str::<HTDate>(&json).unwrap(), date);
+        let mut integers = Vec::new();
+        date.serialize(Integers(&mut integers)).unwrap();
+        // Lesser Apress is the 8th month
+        assert_eq!(integers, vec![2019, 7, 5, 31 * 6000 + 2000]);
+        assert_eq!(HTDate::deserialize(FromIntegers(integers.iter())), Ok(date));
+        assert!(HTDate::deserialize(FromIntegers([2019, 10, 5, 0].iter())).is_err());
+        assert!(HTDate::deserialize(FromIntegers([2019, 7, 25, 0].iter())).is_err());
+        assert!(HTDate::deserialize(FromIntegers([2019, 7].iter())).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Token {
+        #[serde(with = "super::date_or_datetime")]
+        expires: HTDate,
+    }
+
+    #[test]
+    fn accepts_date_only_and_full_strings() {
+        let start_of_day = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        let token: Token = serde_json::from_str(r#"{"expires":"2019-GA-01"}"#).unwrap();
+        assert_eq!(token.expires, start_of_day);
+        let token: Token = serde_json::from_str(r#"{"expires":"2019-GA-01T31S2000R"}"#).unwrap();
+        assert_eq!(token.expires.second, 31 * 6000 + 2000);
+        assert_eq!(serde_json::to_string(&Token { expires: start_of_day }).unwrap(), r#"{"expires":"2019-GA-01T00S0000R"}"#);
+        assert!(serde_json::from_str::<Token>(r#"{"expires":"2019-XA-01"}"#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super::compact_string")]
+        compact: HTDate,
+        #[serde(with = "super::epoch_ticks")]
+        ticks: HTDate,
+        #[serde(with = "super::components")]
+        parts: HTDate,
+    }
+
+    #[test]
+    fn picks_a_representation_per_field() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        let record = Record { compact: date, ticks: date, parts: date };
+        let json = serde_json::to_string(&record).unwrap();
+        let ticks = date.checked_epoch().unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"compact":"2019LA05T31S2000R","ticks":{},"parts":{{"year":2019,"monthStatus":"Lesser","month":"Apress","day":5,"sks":31,"rem":2000}}}}"#,
+                ticks
+            )
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn rejects_invalid_components() {
+        #[derive(Debug, Deserialize)]
+        struct Parts {
+            #[serde(with = "super::components")]
+            #[allow(dead_code)]
+            parts: HTDate,
+        }
+        let parts = |body: &str| serde_json::from_str::<Parts>(&format!(r#"{{"parts":{{{}}}}}"#, body));
+        assert!(parts(r#""year":2019,"monthStatus":"Greater","month":"Apress","day":5,"sks":31,"rem":2000"#).is_ok());
+        assert!(parts(r#""year":2019,"monthStatus":"Greater","month":"Apress","day":25,"sks":31,"rem":2000"#).is_err());
+        assert!(parts(r#""year":2019,"monthStatus":"Greater","month":"Apress","day":5,"sks":31,"rem":6000"#).is_err());
+        assert!(parts(r#""year":2019,"monthStatus":"Middle","month":"Apress","day":5,"sks":31,"rem":0"#).is_err());
+        assert!(parts(r#""year":2019,"monthStatus":"Greater","month":"Apress","day":5,"sks":31"#).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use crate::gregorian::HTEpochMapping;
+use crate::HTDate;
+
+// swapping the timestamp of syslog/journald style lines between RFC3339 and HT dates, either
+// through an HTEpochMapping or a bridge closure the caller supplies
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+// offsets of the first space separated token for which is_timestamp holds
+fn find_token(line: &str, is_timestamp: impl Fn(&str) -> bool) -> Option<(usize, usize)> {
+    let mut start = 0;
+    for token in line.split(' ') {
+        let end = start + token.len();
+        if is_timestamp(token) {
+            return Some((start, end));
+        }
+        start = end + 1;
+    }
+    None
+}
+
+// "2003-10-11T22:14:15Z", with optional fractional seconds and a Z, +HH:MM or +HHMM offset
+pub fn is_rfc3339(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    if bytes.len() < 20 || !bytes.is_ascii() {
+        return false;
+    }
+    let digits = |range: core::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_digit);
+    let fixed = digits(0..4)
+        && bytes[4] == b'-'
+        && digits(5..7)
+        && bytes[7] == b'-'
+        && digits(8..10)
+        && matches!(bytes[10], b'T' | b't')
+        && digits(11..13)
+        && bytes[13] == b':'
+        && digits(14..16)
+        && bytes[16] == b':'
+        && digits(17..19);
+    if !fixed {
+        return false;
+    }
+    let mut rest = &token[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+    match rest.as_bytes() {
+        [b'Z' | b'z'] => true,
+        [b'+' | b'-', h1, h2, b':', m1, m2] | [b'+' | b'-', h1, h2, m1, m2] => {
+            [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+// replaces the first RFC3339 timestamp in the line with the HT date the bridge gives for it.
+// None if no timestamp is found or the bridge can't convert it
+pub fn to_ht_line(line: &str, bridge: impl Fn(&str) -> Option<HTDate>) -> Option<String> {
+    let (start, end) = find_token(line, is_rfc3339)?;
+    let date = bridge(&line[start..end])?;
+    Some(format!("{}{}{}", &line[..start], date, &line[end..]))
+}
+
+// the other way round, for lines already written with HT dates
+pub fn from_ht_line(line: &str, bridge: impl Fn(&HTDate) -> Option<String>) -> Option<String> {
+    let (start, end) = find_token(line, |token| HTDate::interpret_string(token).is_ok())?;
+    let date = HTDate::interpret_string(&line[start..end]).ok()?;
+    let timestamp = bridge(&date)?;
+    Some(format!("{}{}{}", &line[..start], timestamp, &line[end..]))
+}
+
+// the same, converting through the mapping
+pub fn to_ht_line_with(line: &str, mapping: &HTEpochMapping) -> Option<String> {
+    to_ht_line(line, |timestamp| mapping.from_unix_nanos(rfc3339_to_unix_nanos(timestamp)?))
+}
+
+// written back in UTC with a Z, with as many fractional digits as the tick needs
+pub fn from_ht_line_with(line: &str, mapping: &HTEpochMapping) -> Option<String> {
+    from_ht_line(line, |date| unix_nanos_to_rfc3339(mapping.to_unix_nanos(date)?))
+}
+
+// days from 1970-01-01 to a proleptic gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// the other way round, (year, month, day)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    (year_of_era + era * 400 + (month <= 2) as i64, month, day)
+}
+
+// None for anything is_rfc3339 rejects or with fields out of range. digits past nanoseconds are
+// dropped
+fn rfc3339_to_unix_nanos(token: &str) -> Option<i128> {
+    if !is_rfc3339(token) {
+        return None;
+    }
+    let number = |range: core::ops::Range<usize>| token[range].parse::<i64>().ok();
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    let month_length = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    // 60 for a leap second, which lands on the next second like most clocks do
+    if day == 0 || day > month_length || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let mut rest = &token[19..];
+    let mut nanos = 0i128;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        for (place, digit) in fraction.bytes().take(len.min(9)).enumerate() {
+            nanos += (digit - b'0') as i128 * 10i128.pow(8 - place as u32);
+        }
+        rest = &fraction[len..];
+    }
+    let offset_seconds = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign, h1, h2, b':', m1, m2] | [sign, h1, h2, m1, m2] => {
+            let digit = |b: &u8| (b - b'0') as i64;
+            let (hours, minutes) = (digit(h1) * 10 + digit(h2), digit(m1) * 10 + digit(m2));
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let magnitude = hours * 3600 + minutes * 60;
+            if *sign == b'-' {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+        _ => return None,
+    };
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    Some(seconds as i128 * NANOS_PER_SECOND + nanos)
+}
+
+// None outside the four digit years
+fn unix_nanos_to_rfc3339(nanos: i128) -> Option<String> {
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+    let subsec = nanos.rem_euclid(NANOS_PER_SECOND);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+    let time = seconds.rem_euclid(86_400);
+    let mut timestamp = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, time / 3600, time / 60 % 60, time % 60);
+    if subsec != 0 {
+        let fraction = format!("{:09}", subsec);
+        // writing to a String can't fail
+        let _ = write!(timestamp, ".{}", fraction.trim_end_matches('0'));
+    }
+    timestamp.push('Z');
+    Some(timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    fn date() -> HTDate {
+        HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000)
+    }
+
+    #[test]
+    fn recognizes_rfc3339() {
+        assert!(is_rfc3339("2003-10-11T22:14:15Z"));
+        assert!(is_rfc3339("2003-10-11T22:14:15.003Z"));
+        assert!(is_rfc3339("2003-10-11T22:14:15+02:00"));
+        assert!(is_rfc3339("2003-10-11T22:14:15-0700"));
+        assert!(!is_rfc3339("2003-10-11T22:14:15"));
+        assert!(!is_rfc3339("2003-10-11T22:14:15.Z"));
+        assert!(!is_rfc3339("2003-10-11 22:14:15Z"));
+    }
+
+    #[test]
+    fn swaps_timestamps_both_ways() {
+        let line = "<34>1 2003-10-11T22:14:15.003Z mymachine su - ID47 - failed";
+        let converted = to_ht_line(line, |_| Some(date())).unwrap();
+        assert_eq!(converted, "<34>1 2019-GA-01T31S2000R mymachine su - ID47 - failed");
+        let back = from_ht_line(&converted, |_| Some("2003-10-11T22:14:15.003Z".to_string())).unwrap();
+        assert_eq!(back, line);
+    }
+
+    #[test]
+    fn converts_through_a_mapping() {
+        let mapping = HTEpochMapping::DEFAULT;
+        // 22:14:15.072 is a whole number of 144ms ticks into the day
+        let line = "<34>1 2003-10-11T22:14:15.072Z mymachine su - ID47 - failed";
+        let expected = mapping.from_unix_parts(1_065_910_455, 72_000_000).unwrap();
+        let converted = to_ht_line_with(line, &mapping).unwrap();
+        assert_eq!(converted, format!("<34>1 {} mymachine su - ID47 - failed", expected));
+        assert_eq!(from_ht_line_with(&converted, &mapping).unwrap(), line);
+        // offsets are folded into the instant, and it's written back in UTC
+        let ahead = to_ht_line_with("2003-10-11T23:14:15.072+01:00 up", &mapping).unwrap();
+        assert_eq!(ahead, format!("{} up", expected));
+        assert_eq!(from_ht_line_with(&ahead, &mapping).unwrap(), "2003-10-11T22:14:15.072Z up");
+        assert_eq!(to_ht_line_with("2003-02-29T00:00:00Z leap", &mapping), None);
+        assert_eq!(to_ht_line_with("2003-10-11T23:14:15+99:99 up", &mapping), None);
+        assert_eq!(to_ht_line_with("2003-10-11T23:14:15+2360 up", &mapping), None);
+        assert!(to_ht_line_with("2003-10-11T23:14:15-2359 up", &mapping).is_some());
+        assert_eq!(to_ht_line_with("1969-12-31T23:59:59Z early", &mapping), None);
+    }
+
+    #[test]
+    fn counts_gregorian_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        for days in [-719_468, -1, 0, 11_016, 11_017, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(2_932_896), (9999, 12, 31));
+    }
+
+    #[test]
+    fn leaves_lines_without_timestamps() {
+        assert_eq!(to_ht_line("no timestamp here", |_| Some(date())), None);
+        assert_eq!(to_ht_line("2003-10-11T22:14:15Z late", |_| None), None);
+        assert_eq!(from_ht_line("2003-10-11T22:14:15Z", |_| Some(String::new())), None);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "ht_cal")]
+use ht_cal::datetime::HDateTime;
+use crate::{HTDate, Month, MonthStatus};
+
+// shared accessors for everything in the crate that represents a point in ht time, so helpers can
+// take any of them
+pub trait HTTemporal {
+    fn year(&self) -> u128;
+    fn month(&self) -> (MonthStatus, Month);
+    fn day(&self) -> u8;
+    fn second(&self) -> u128;
+
+    fn to_htdate(&self) -> HTDate {
+        let (month_status, month) = self.month();
+        HTDate::new(self.year(), month_status, month, self.day(), self.second())
+    }
+
+    // seconds since the start of year 0, saturating for years too large to count in a u128
+    fn to_epoch(&self) -> u128 {
+        self.to_htdate().checked_epoch().unwrap_or(u128::MAX)
+    }
+}
+
+impl HTTemporal for HTDate {
+    fn year(&self) -> u128 {
+        self.year
+    }
+
+    fn month(&self) -> (MonthStatus, Month) {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn second(&self) -> u128 {
+        self.second
+    }
+
+    fn to_htdate(&self) -> HTDate {
+        *self
+    }
+}
+
+#[cfg(feature = "ht_cal")]
+impl HTTemporal for HDateTime {
+    fn year(&self) -> u128 {
+        self.year
+    }
+
+    fn month(&self) -> (MonthStatus, Month) {
+        crate::from_ht_cal_month(self.month)
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn second(&self) -> u128 {
+        self.second.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DAYS_PER_YEAR, SECONDS_PER_DAY};
+
+    fn epoch_of<T: HTTemporal>(value: &T) -> u128 {
+        value.to_epoch()
+    }
+
+    #[test]
+    fn counts_from_year_zero() {
+        assert_eq!(epoch_of(&HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0)), 0);
+        assert_eq!(epoch_of(&HTDate::new(0, MonthStatus::Greater, Month::Zero, 2, 5)), SECONDS_PER_DAY + 5);
+        assert_eq!(epoch_of(&HTDate::new(1, MonthStatus::Greater, Month::Zero, 1, 0)), DAYS_PER_YEAR as u128 * SECONDS_PER_DAY);
+        assert_eq!(epoch_of(&HTDate::new(u128::MAX, MonthStatus::Greater, Month::Zero, 1, 0)), u128::MAX);
+    }
+
+    #[cfg(feature = "ht_cal")]
+    #[test]
+    fn hdatetime_agrees_with_htdate() {
+        let date = HTDate::new(2019, MonthStatus::Lesser, Month::Smosh, 12, 31 * 6000 + 2000);
+        let hdt = date.to_hdatetime();
+        assert_eq!(hdt.to_htdate(), date);
+        assert_eq!(epoch_of(&hdt), epoch_of(&date));
+    }
+}
@@ -0,0 +1,80 @@
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::gregorian::HTEpochMapping;
+use crate::HTDate;
+
+// the same conversions as the chrono ones, for the time crate. None before the HT epoch or past
+// the range time can hold
+impl HTDate {
+    // always at UTC
+    pub fn to_offset_datetime(&self) -> Option<OffsetDateTime> {
+        self.to_offset_datetime_with(&HTEpochMapping::DEFAULT)
+    }
+
+    pub fn to_offset_datetime_with(&self, mapping: &HTEpochMapping) -> Option<OffsetDateTime> {
+        OffsetDateTime::from_unix_timestamp_nanos(mapping.to_unix_nanos(self)?).ok()
+    }
+
+    // the instant, whatever offset it's written at. rounded down to the tick
+    pub fn from_offset_datetime(datetime: &OffsetDateTime) -> Option<Self> {
+        HTDate::from_offset_datetime_with(datetime, &HTEpochMapping::DEFAULT)
+    }
+
+    pub fn from_offset_datetime_with(datetime: &OffsetDateTime, mapping: &HTEpochMapping) -> Option<Self> {
+        mapping.from_unix_nanos(datetime.unix_timestamp_nanos())
+    }
+
+    // UTC wall clock time
+    pub fn to_primitive_datetime(&self) -> Option<PrimitiveDateTime> {
+        self.to_primitive_datetime_with(&HTEpochMapping::DEFAULT)
+    }
+
+    pub fn to_primitive_datetime_with(&self, mapping: &HTEpochMapping) -> Option<PrimitiveDateTime> {
+        let utc = self.to_offset_datetime_with(mapping)?;
+        Some(PrimitiveDateTime::new(utc.date(), utc.time()))
+    }
+
+    // read as UTC
+    pub fn from_primitive_datetime(datetime: &PrimitiveDateTime) -> Option<Self> {
+        HTDate::from_primitive_datetime_with(datetime, &HTEpochMapping::DEFAULT)
+    }
+
+    pub fn from_primitive_datetime_with(datetime: &PrimitiveDateTime, mapping: &HTEpochMapping) -> Option<Self> {
+        HTDate::from_offset_datetime_with(&datetime.assume_utc(), mapping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+    use time::{Date, UtcOffset};
+
+    fn primitive(year: i32, month: time::Month, day: u8, hour: u8) -> PrimitiveDateTime {
+        Date::from_calendar_date(year, month, day).unwrap().with_hms(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn converts_at_the_default_mapping() {
+        let epoch = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(epoch.to_primitive_datetime(), Some(primitive(1970, time::Month::January, 1, 0)));
+        assert_eq!(epoch.to_offset_datetime(), Some(primitive(1970, time::Month::January, 1, 0).assume_utc()));
+        let noon = HTDate::new(0, MonthStatus::Greater, Month::Niktvirin, 1, 300_000);
+        assert_eq!(HTDate::from_primitive_datetime(&primitive(1970, time::Month::January, 25, 12)), Some(noon));
+        // two hours ahead of UTC is the same instant as 10:00 UTC
+        let ahead = primitive(1970, time::Month::January, 25, 12).assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(HTDate::from_offset_datetime(&ahead), Some(HTDate::new(0, MonthStatus::Greater, Month::Niktvirin, 1, 250_000)));
+        assert_eq!(HTDate::from_primitive_datetime(&primitive(1969, time::Month::December, 31, 23)), None);
+        let date = HTDate::new(56, MonthStatus::Lesser, Month::Apress, 5, 31 * 6000 + 2000);
+        assert_eq!(HTDate::from_offset_datetime(&date.to_offset_datetime().unwrap()), Some(date));
+    }
+
+    #[test]
+    fn converts_with_another_mapping() {
+        let mapping = HTEpochMapping::from_unix_seconds(86_400, 144_000_000);
+        let epoch = HTDate::new(0, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(epoch.to_primitive_datetime_with(&mapping), Some(primitive(1970, time::Month::January, 2, 0)));
+        // far enough out that time can't hold it
+        assert_eq!(HTDate::new(1_000_000_000, MonthStatus::Greater, Month::Zero, 1, 0).to_offset_datetime_with(&mapping), None);
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt::{Display, Formatter};
+
+use crate::duration::HTDuration;
+use crate::{HTDate, HTParseError, SECONDS_PER_DAY, SECONDS_PER_SKS};
+
+// a time of day on its own, ticks since the start of the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HTTime {
+    second: u32,
+}
+
+impl HTTime {
+    pub const MIDNIGHT: HTTime = HTTime { second: 0 };
+
+    pub fn new(sks: u8, rem: u16) -> Result<Self, HTParseError> {
+        if rem as u128 >= SECONDS_PER_SKS {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        HTTime::from_second(sks as u128 * SECONDS_PER_SKS + rem as u128)
+    }
+
+    pub fn from_second(second: u128) -> Result<Self, HTParseError> {
+        if second >= SECONDS_PER_DAY {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(HTTime { second: second as u32 })
+    }
+
+    pub fn sks(&self) -> u8 {
+        (self.second as u128 / SECONDS_PER_SKS) as u8
+    }
+
+    pub fn rem(&self) -> u16 {
+        (self.second as u128 % SECONDS_PER_SKS) as u16
+    }
+
+    pub fn second(&self) -> u128 {
+        self.second as u128
+    }
+
+    // wraps around the day, giving back how many day boundaries were crossed: negative going
+    // backwards past midnight. saturates for durations of more than i64::MAX days
+    pub fn adding(&self, duration: HTDuration) -> (HTTime, i64) {
+        let day = SECONDS_PER_DAY as i128;
+        // splitting off whole days first keeps the sum from overflowing
+        let total = duration.ticks() % day + self.second as i128;
+        let days = duration.ticks() / day + total.div_euclid(day);
+        let carried = i64::try_from(days).unwrap_or(if days < 0 { i64::MIN } else { i64::MAX });
+        (HTTime { second: total.rem_euclid(day) as u32 }, carried)
+    }
+}
+
+// 31S2000R, the time part of the canonical string
+impl Display for HTTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0>2}S{:0>4}R", self.sks(), self.rem())
+    }
+}
+
+impl HTDate {
+    // None for a second past the end of the day
+    pub fn time(&self) -> Option<HTTime> {
+        HTTime::from_second(self.second).ok()
+    }
+
+    pub fn with_time(&self, time: HTTime) -> Self {
+        HTDate { second: time.second(), ..*self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn reports_days_carried() {
+        let late = HTTime::new(95, 0).unwrap();
+        assert_eq!(late.adding(HTDuration::from_sks(3)), (HTTime::new(98, 0).unwrap(), 0));
+        assert_eq!(late.adding(HTDuration::from_sks(10)), (HTTime::new(5, 0).unwrap(), 1));
+        assert_eq!(late.adding(HTDuration::from_sks(5)), (HTTime::MIDNIGHT, 1));
+        assert_eq!(late.adding(HTDuration::from_days(3)), (late, 3));
+        assert_eq!(HTTime::MIDNIGHT.adding(HTDuration::from_ticks(-1)), (HTTime::new(99, 5999).unwrap(), -1));
+        assert_eq!(late.adding(HTDuration::from_days(-2) + HTDuration::from_sks(-96)), (HTTime::new(99, 0).unwrap(), -3));
+        assert_eq!(late.adding(HTDuration::from_ticks(i128::MAX)).1, i64::MAX);
+        assert_eq!(late.adding(HTDuration::from_ticks(i128::MIN)).1, i64::MIN);
+    }
+
+    #[test]
+    fn builds_and_writes_times() {
+        assert_eq!(HTTime::new(31, 2000).unwrap().to_string(), "31S2000R");
+        assert_eq!(HTTime::new(31, 6000), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTTime::new(100, 0), Err(HTParseError::OtherwiseInvalidDate));
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let time = date.time().unwrap();
+        assert_eq!((time.sks(), time.rem(), time.second()), (31, 2000, date.second));
+        assert_eq!(date.with_time(HTTime::MIDNIGHT), date.start_of_day());
+        assert_eq!(HTDate { second: SECONDS_PER_DAY, ..date }.time(), None);
+    }
+}
@@ -0,0 +1,134 @@
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Sub};
+
+use crate::duration::HTDuration;
+use crate::temporal::HTTemporal;
+use crate::{HTDate, HTParseError, Month, MonthStatus};
+
+// ticks since the epoch as a bare integer, for code that sorts and subtracts lots of instants.
+// converts to and from normalized HTDates without loss
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct HTTimestamp(pub u128);
+
+impl HTTimestamp {
+    pub const EPOCH: HTTimestamp = HTTimestamp(0);
+
+    pub fn checked_add(&self, duration: HTDuration) -> Option<Self> {
+        let ticks = if duration.is_negative() {
+            self.0.checked_sub(duration.ticks().unsigned_abs())?
+        } else {
+            self.0.checked_add(duration.ticks() as u128)?
+        };
+        Some(HTTimestamp(ticks))
+    }
+
+    // None if the gap doesn't fit a duration
+    pub fn checked_duration_since(&self, earlier: HTTimestamp) -> Option<HTDuration> {
+        if self.0 >= earlier.0 {
+            i128::try_from(self.0 - earlier.0).ok().map(HTDuration::from_ticks)
+        } else {
+            i128::try_from(earlier.0 - self.0).ok().map(|ticks| HTDuration::from_ticks(-ticks))
+        }
+    }
+}
+
+impl From<HTTimestamp> for HTDate {
+    fn from(timestamp: HTTimestamp) -> Self {
+        HTDate::from_epoch(timestamp.0)
+    }
+}
+
+// Err for dates past the last tick a u128 can count
+impl TryFrom<HTDate> for HTTimestamp {
+    type Error = HTParseError;
+
+    fn try_from(date: HTDate) -> Result<Self, Self::Error> {
+        date.checked_epoch().map(HTTimestamp).ok_or(HTParseError::OtherwiseInvalidDate)
+    }
+}
+
+// the operators panic on overflow like the ones on HTDuration
+impl Add<HTDuration> for HTTimestamp {
+    type Output = HTTimestamp;
+
+    fn add(self, duration: HTDuration) -> HTTimestamp {
+        self.checked_add(duration).expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl Sub<HTDuration> for HTTimestamp {
+    type Output = HTTimestamp;
+
+    fn sub(self, duration: HTDuration) -> HTTimestamp {
+        duration.checked_neg().and_then(|negated| self.checked_add(negated)).expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl Sub for HTTimestamp {
+    type Output = HTDuration;
+
+    fn sub(self, other: HTTimestamp) -> HTDuration {
+        self.checked_duration_since(other).expect("timestamps too far apart to subtract")
+    }
+}
+
+impl HTTemporal for HTTimestamp {
+    fn year(&self) -> u128 {
+        self.to_htdate().year
+    }
+
+    fn month(&self) -> (MonthStatus, Month) {
+        self.to_htdate().month
+    }
+
+    fn day(&self) -> u8 {
+        self.to_htdate().day
+    }
+
+    fn second(&self) -> u128 {
+        self.to_htdate().second
+    }
+
+    fn to_htdate(&self) -> HTDate {
+        HTDate::from(*self)
+    }
+
+    fn to_epoch(&self) -> u128 {
+        self.0
+    }
+}
+
+// the date it stands for, in the canonical form
+impl Display for HTTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_htdate(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_without_loss() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let timestamp = HTTimestamp::try_from(date).unwrap();
+        assert_eq!(timestamp.0, date.checked_epoch().unwrap());
+        assert_eq!(HTDate::from(timestamp), date);
+        assert_eq!(timestamp.to_string(), date.to_string());
+        assert_eq!(timestamp.to_epoch(), timestamp.0);
+        assert_eq!(timestamp.day(), 1);
+        assert_eq!(HTTimestamp::try_from(HTDate::MAX), Err(HTParseError::OtherwiseInvalidDate));
+    }
+
+    #[test]
+    fn does_arithmetic_on_ticks() {
+        let timestamp = HTTimestamp(1_000);
+        assert_eq!(timestamp + HTDuration::from_ticks(500), HTTimestamp(1_500));
+        assert_eq!(timestamp - HTDuration::from_ticks(500), HTTimestamp(500));
+        assert_eq!(HTTimestamp(500) - timestamp, HTDuration::from_ticks(-500));
+        assert_eq!(timestamp.checked_add(HTDuration::from_ticks(-1_001)), None);
+        assert!(HTTimestamp(1) > HTTimestamp::EPOCH);
+    }
+}
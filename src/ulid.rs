@@ -0,0 +1,123 @@
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::{HTDate, HTParseError};
+
+// 128 bit id of 64 bits of epoch ticks then 64 caller supplied random bits, written as 26
+// characters of crockford base32 like a ULID. ids sort by their date first, as numbers and as
+// strings. the crate has no clock or random source, so both halves are passed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HTUlid {
+    ticks: u64,
+    random: u64,
+}
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl HTUlid {
+    // dates more than u64::MAX ticks after the epoch don't fit
+    pub fn new(date: &HTDate, random: u64) -> Result<Self, HTParseError> {
+        let ticks = date.checked_epoch().and_then(|ticks| u64::try_from(ticks).ok()).ok_or(HTParseError::OtherwiseInvalidDate)?;
+        Ok(HTUlid { ticks, random })
+    }
+
+    pub fn date(&self) -> HTDate {
+        HTDate::from_epoch(self.ticks as u128)
+    }
+
+    pub fn random(&self) -> u64 {
+        self.random
+    }
+
+    pub fn to_u128(&self) -> u128 {
+        (self.ticks as u128) << 64 | self.random as u128
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        HTUlid {
+            ticks: (value >> 64) as u64,
+            random: value as u64,
+        }
+    }
+
+    // case-insensitive, with I and L read as 1 and O as 0 the way crockford base32 allows
+    pub fn interpret_string(input: &str) -> Result<Self, HTParseError> {
+        if input.len() != 26 {
+            return Err(HTParseError::MalformedString);
+        }
+        let mut value = 0u128;
+        for (index, c) in input.bytes().enumerate() {
+            let c = match c.to_ascii_uppercase() {
+                b'I' | b'L' => b'1',
+                b'O' => b'0',
+                c => c,
+            };
+            let digit = CROCKFORD.iter().position(|d| *d == c).ok_or(HTParseError::MalformedString)? as u128;
+            // the first character only carries the top 3 bits
+            if index == 0 && digit > 7 {
+                return Err(HTParseError::MalformedString);
+            }
+            value = value << 5 | digit;
+        }
+        Ok(HTUlid::from_u128(value))
+    }
+}
+
+impl Display for HTUlid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let value = self.to_u128();
+        let mut out = [0u8; 26];
+        for (index, c) in out.iter_mut().enumerate() {
+            *c = CROCKFORD[(value >> (125 - 5 * index) & 31) as usize];
+        }
+        // only crockford digits went in
+        f.pad(core::str::from_utf8(&out).unwrap_or_default())
+    }
+}
+
+impl FromStr for HTUlid {
+    type Err = HTParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HTUlid::interpret_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn round_trips_strings() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 31 * 6000 + 2000);
+        let id = HTUlid::new(&date, 0xdead_beef_0123_4567).unwrap();
+        let string = id.to_string();
+        assert_eq!(string.len(), 26);
+        assert_eq!(string.parse::<HTUlid>(), Ok(id));
+        assert_eq!(HTUlid::interpret_string(&string.to_ascii_lowercase()), Ok(id));
+        assert_eq!(id.date(), date);
+        assert_eq!(id.random(), 0xdead_beef_0123_4567);
+        assert_eq!(HTUlid::from_u128(u128::MAX).to_string(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert_eq!(HTUlid::from_u128(0).to_string(), "00000000000000000000000000");
+    }
+
+    #[test]
+    fn sorts_by_date() {
+        let earlier = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 0);
+        let later = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 1, 1);
+        let a = HTUlid::new(&earlier, u64::MAX).unwrap();
+        let b = HTUlid::new(&later, 0).unwrap();
+        assert!(a < b);
+        assert!(a.to_string() < b.to_string());
+    }
+
+    #[test]
+    fn rejects_bad_strings() {
+        assert_eq!(HTUlid::interpret_string("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Err(HTParseError::MalformedString));
+        assert_eq!(HTUlid::interpret_string("0000000000000000000000000U"), Err(HTParseError::MalformedString));
+        assert_eq!(HTUlid::interpret_string("0000"), Err(HTParseError::MalformedString));
+        let huge = HTDate::new(u128::MAX / 2, MonthStatus::Greater, Month::Zero, 1, 0);
+        assert_eq!(HTUlid::new(&huge, 0), Err(HTParseError::OtherwiseInvalidDate));
+    }
+}
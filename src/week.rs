@@ -0,0 +1,94 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{HTDate, HTParseError, DAYS_PER_WEEK, WEEKS_PER_YEAR};
+
+// one run of the day cycle, numbered within its year like week_of_year. ordered by year then week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HTWeek {
+    year: u128,
+    week: u8,
+}
+
+impl HTWeek {
+    pub fn new(year: u128, week: u8) -> Result<Self, HTParseError> {
+        if week == 0 || week > WEEKS_PER_YEAR {
+            return Err(HTParseError::OtherwiseInvalidDate);
+        }
+        Ok(HTWeek { year, week })
+    }
+
+    pub fn year(&self) -> u128 {
+        self.year
+    }
+
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+
+    // the Firstday, at the start of the day
+    pub fn first_day(&self) -> HTDate {
+        self.day(1)
+    }
+
+    pub fn last_day(&self) -> HTDate {
+        self.day(DAYS_PER_WEEK)
+    }
+
+    // the start of each day of the week in order
+    pub fn days(&self) -> impl Iterator<Item = HTDate> {
+        let week = *self;
+        (1..=DAYS_PER_WEEK).map(move |day_of_week| week.day(day_of_week))
+    }
+
+    pub fn contains(&self, date: &HTDate) -> bool {
+        date.week() == *self
+    }
+
+    fn day(&self, day_of_week: u8) -> HTDate {
+        // week and day_of_week are both in range
+        HTDate::from_week_date(self.year, self.week, day_of_week).unwrap_or(HTDate::EPOCH)
+    }
+}
+
+// 2019-W07, the week part of to_week_date_string
+impl Display for HTWeek {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:0>4}-W{:0>2}", self.year, self.week)
+    }
+}
+
+impl HTDate {
+    pub fn week(&self) -> HTWeek {
+        HTWeek {
+            year: self.year,
+            week: self.week_of_year(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    #[test]
+    fn converts_between_dates_and_weeks() {
+        let date = HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 9, 100);
+        let week = date.week();
+        assert_eq!(week, HTWeek::new(2019, 14).unwrap());
+        assert_eq!(week.to_string(), "2019-W14");
+        assert_eq!(week.first_day(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 7, 0));
+        assert_eq!(week.last_day(), HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 12, 0));
+        assert!(week.days().all(|day| week.contains(&day)));
+        assert_eq!(week.days().count(), DAYS_PER_WEEK as usize);
+        assert!(!week.contains(&HTDate::new(2019, MonthStatus::Greater, Month::Smosh, 13, 0)));
+    }
+
+    #[test]
+    fn orders_and_validates_weeks() {
+        assert!(HTWeek::new(2019, 40).unwrap() < HTWeek::new(2020, 1).unwrap());
+        assert!(HTWeek::new(2019, 2).unwrap() > HTWeek::new(2019, 1).unwrap());
+        assert_eq!(HTWeek::new(2019, 0), Err(HTParseError::OtherwiseInvalidDate));
+        assert_eq!(HTWeek::new(2019, 41), Err(HTParseError::OtherwiseInvalidDate));
+    }
+}
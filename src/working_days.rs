@@ -0,0 +1,120 @@
+use alloc::{vec, vec::Vec};
+use crate::{CycleDay, HTDate, DAYS_PER_WEEK};
+
+// which days are skipped by working day arithmetic: some days of the cycle and some dates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestDayConfig {
+    pub rest_days: Vec<CycleDay>,
+    // only the year, month and day are compared
+    pub holidays: Vec<HTDate>,
+}
+
+impl Default for RestDayConfig {
+    fn default() -> Self {
+        RestDayConfig {
+            rest_days: vec![CycleDay::Restday],
+            holidays: Vec::new(),
+        }
+    }
+}
+
+impl RestDayConfig {
+    pub fn is_working_day(&self, date: &HTDate) -> bool {
+        !self.rest_days.contains(&date.day_of_cycle()) && !self.holidays.iter().any(|holiday| same_day(holiday, date))
+    }
+}
+
+fn same_day(a: &HTDate, b: &HTDate) -> bool {
+    a.year == b.year && a.month == b.month && a.day == b.day
+}
+
+impl HTDate {
+    // the nth working day after this one, at the same time of day. steps a day at a time, so None
+    // if no day of the cycle is a working day, or on running out of years
+    pub fn add_working_days(&self, n: u64, config: &RestDayConfig) -> Option<Self> {
+        if CycleDay::ALL.iter().all(|day| config.rest_days.contains(day)) && n > 0 {
+            return None;
+        }
+        let mut date = *self;
+        let mut remaining = n;
+        while remaining > 0 {
+            date = date.checked_add_days(1)?;
+            if config.is_working_day(&date) {
+                remaining -= 1;
+            }
+        }
+        Some(date)
+    }
+
+    // working days after start up to and including end, so that
+    // start.add_working_days(n) == end gives n. negative when end is before start
+    pub fn working_days_between(start: &HTDate, end: &HTDate, config: &RestDayConfig) -> Option<i128> {
+        let (from, to) = (start.to_day_number()?, end.to_day_number()?);
+        if to < from {
+            return HTDate::working_days_between(end, start, config).map(|days| -days);
+        }
+        let span = to - from;
+        let weeks = span / DAYS_PER_WEEK as i128;
+        let working_per_week = CycleDay::ALL.iter().filter(|day| !config.rest_days.contains(day)).count() as i128;
+        let mut count = weeks * working_per_week;
+        // the days left over after whole weeks, and the holidays that fall on otherwise working days
+        for day_number in from + weeks * DAYS_PER_WEEK as i128 + 1..=to {
+            if !config.rest_days.contains(&HTDate::from_day_number(day_number)?.day_of_cycle()) {
+                count += 1;
+            }
+        }
+        let mut holidays: Vec<i128> = config
+            .holidays
+            .iter()
+            .filter(|holiday| !config.rest_days.contains(&holiday.day_of_cycle()))
+            .filter_map(|holiday| holiday.to_day_number())
+            .filter(|day_number| (from + 1..=to).contains(day_number))
+            .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        Some(count - holidays.len() as i128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Month, MonthStatus};
+
+    fn date(day: u8) -> HTDate {
+        HTDate::new(2019, MonthStatus::Greater, Month::Apress, day, 100)
+    }
+
+    #[test]
+    fn skips_rest_days_and_holidays() {
+        let config = RestDayConfig::default();
+        // the 6th is a Restday
+        assert_eq!(date(5).add_working_days(1, &config), Some(date(7)));
+        assert_eq!(date(1).add_working_days(4, &config), Some(date(5)));
+        assert_eq!(date(1).add_working_days(5, &config), Some(date(7)));
+        assert_eq!(date(1).add_working_days(0, &config), Some(date(1)));
+        let holidays = RestDayConfig {
+            holidays: vec![date(7).start_of_day()],
+            ..RestDayConfig::default()
+        };
+        assert_eq!(date(5).add_working_days(1, &holidays), Some(date(8)));
+        let no_work = RestDayConfig {
+            rest_days: CycleDay::ALL.to_vec(),
+            holidays: Vec::new(),
+        };
+        assert_eq!(date(1).add_working_days(1, &no_work), None);
+    }
+
+    #[test]
+    fn counts_working_days_between() {
+        let config = RestDayConfig {
+            rest_days: vec![CycleDay::Fifthday, CycleDay::Restday],
+            holidays: vec![date(9), date(9), date(12)],
+        };
+        for n in 0..30 {
+            let end = date(1).add_working_days(n, &config).unwrap();
+            assert_eq!(HTDate::working_days_between(&date(1), &end, &config), Some(n as i128));
+            assert_eq!(HTDate::working_days_between(&end, &date(1), &config), Some(-(n as i128)));
+        }
+    }
+}
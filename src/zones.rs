@@ -0,0 +1,275 @@
+use alloc::{string::String, vec::Vec};
+use crate::duration::HTDuration;
+use crate::offset::{HTDateTimeOffset, HTOffset};
+use crate::temporal::HTTemporal;
+use crate::{month_index, HTDate, Month, MonthStatus};
+
+// extra offset for part of the year, from the first month to the last inclusive. last before
+// first wraps around the new year
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTSeasonalShift {
+    pub first: (MonthStatus, Month),
+    pub last: (MonthStatus, Month),
+    pub shift: HTOffset,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HTZone {
+    pub name: String,
+    pub offset: HTOffset,
+    pub seasonal: Option<HTSeasonalShift>,
+}
+
+impl HTZone {
+    // the offset in force at a universal date, the season goes by the universal month.
+    // None if the shift takes the total to a day or more
+    pub fn offset_at(&self, universal: &HTDate) -> Option<HTOffset> {
+        match self.seasonal {
+            Some(season) if in_season(&season, universal.month) => HTOffset::from_ticks(self.offset.ticks() + season.shift.ticks()).ok(),
+            _ => Some(self.offset),
+        }
+    }
+}
+
+fn in_season(season: &HTSeasonalShift, month: (MonthStatus, Month)) -> bool {
+    let (first, last, month) = (month_index(season.first), month_index(season.last), month_index(month));
+    if first <= last {
+        (first..=last).contains(&month)
+    } else {
+        month >= first || month <= last
+    }
+}
+
+// zones by name. see from_json for loading one from a file
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HTZoneDb {
+    zones: Vec<HTZone>,
+}
+
+impl HTZoneDb {
+    pub fn new() -> Self {
+        HTZoneDb::default()
+    }
+
+    // replaces any zone with the same name
+    pub fn insert(&mut self, zone: HTZone) {
+        match self.zones.iter_mut().find(|existing| existing.name == zone.name) {
+            Some(existing) => *existing = zone,
+            None => self.zones.push(zone),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&HTZone> {
+        self.zones.iter().find(|zone| zone.name == name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.zones.iter().map(|zone| zone.name.as_str())
+    }
+
+    // {"Ashkev": {"offset": "+05S0000R", "seasonal": {"first": "GS", "last": "LN", "shift": "+10S0000R"}}},
+    // with "seasonal" optional
+    #[cfg(feature = "serde_json")]
+    pub fn from_json(text: &str) -> Result<Self, crate::HTParseError> {
+        use crate::HTParseError;
+        use serde_json::Value;
+
+        let value: Value = serde_json::from_str(text).map_err(|_| HTParseError::MalformedString)?;
+        fn text_of<'v>(value: &'v Value, key: &str) -> Result<&'v str, HTParseError> {
+            value.get(key).and_then(Value::as_str).ok_or(HTParseError::MalformedString)
+        }
+        fn month_of(value: &Value, key: &str) -> Result<(MonthStatus, Month), HTParseError> {
+            let text = text_of(value, key)?;
+            if text.len() != 2 || !text.is_ascii() {
+                return Err(HTParseError::MalformedString);
+            }
+            crate::parse_month_from_gl_and_m(&text[..1], &text[1..])
+        }
+
+        let mut db = HTZoneDb::new();
+        for (name, zone) in value.as_object().ok_or(HTParseError::MalformedString)? {
+            let seasonal = match zone.get("seasonal") {
+                None | Some(Value::Null) => None,
+                Some(season) => Some(HTSeasonalShift {
+                    first: month_of(season, "first")?,
+                    last: month_of(season, "last")?,
+                    shift: HTOffset::interpret_string(text_of(season, "shift")?)?,
+                }),
+            };
+            db.insert(HTZone {
+                name: name.clone(),
+                offset: HTOffset::interpret_string(text_of(zone, "offset")?)?,
+                seasonal,
+            });
+        }
+        Ok(db)
+    }
+}
+
+// an instant seen from a zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HTZoned<'a> {
+    universal: HTDate,
+    zone: &'a HTZone,
+}
+
+impl<'a> HTZoned<'a> {
+    pub fn new(universal: HTDate, zone: &'a HTZone) -> Self {
+        HTZoned { universal, zone }
+    }
+
+    // the instant a local date in the zone stands for. when a seasonal shift makes a local date
+    // happen twice the earlier instant is taken, and None when it doesn't happen at all
+    pub fn from_local(local: &HTDate, zone: &'a HTZone) -> Option<Self> {
+        let shifted = zone.seasonal.and_then(|season| HTOffset::from_ticks(zone.offset.ticks() + season.shift.ticks()).ok());
+        let mut candidates: Vec<HTDate> = [Some(zone.offset), shifted]
+            .into_iter()
+            .flatten()
+            .filter_map(|offset| HTDateTimeOffset::new(*local, offset).to_universal())
+            .filter(|universal| zone.offset_at(universal).and_then(|offset| HTDateTimeOffset::from_universal(universal, offset)).map(|back| back.date()) == Some(*local))
+            .collect();
+        candidates.sort();
+        Some(HTZoned::new(*candidates.first()?, zone))
+    }
+
+    pub fn universal(&self) -> HTDate {
+        self.universal
+    }
+
+    pub fn zone(&self) -> &'a HTZone {
+        self.zone
+    }
+
+    // the local date and offset, None near the ends of the date range
+    pub fn local(&self) -> Option<HTDateTimeOffset> {
+        HTDateTimeOffset::from_universal(&self.universal, self.zone.offset_at(&self.universal)?)
+    }
+
+    pub fn to_zone<'b>(&self, zone: &'b HTZone) -> HTZoned<'b> {
+        HTZoned::new(self.universal, zone)
+    }
+
+    pub fn checked_add_duration(&self, duration: HTDuration) -> Option<Self> {
+        Some(HTZoned::new(self.universal.checked_add_duration(duration)?, self.zone))
+    }
+}
+
+// the local date, or the universal one near the ends of the range where it has none. to_epoch is
+// always the universal instant, so zoned values compare with everything else
+impl HTTemporal for HTZoned<'_> {
+    fn year(&self) -> u128 {
+        self.to_htdate().year
+    }
+
+    fn month(&self) -> (MonthStatus, Month) {
+        self.to_htdate().month
+    }
+
+    fn day(&self) -> u8 {
+        self.to_htdate().day
+    }
+
+    fn second(&self) -> u128 {
+        self.to_htdate().second
+    }
+
+    fn to_htdate(&self) -> HTDate {
+        self.local().map_or(self.universal, |local| local.date())
+    }
+
+    fn to_epoch(&self) -> u128 {
+        self.universal.to_epoch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zones() -> (HTZone, HTZone) {
+        let ashkev = HTZone {
+            name: "Ashkev".to_string(),
+            offset: HTOffset::from_sks(5).unwrap(),
+            seasonal: Some(HTSeasonalShift {
+                first: (MonthStatus::Greater, Month::Smosh),
+                last: (MonthStatus::Lesser, Month::Niktvirin),
+                shift: HTOffset::from_sks(10).unwrap(),
+            }),
+        };
+        let coast = HTZone {
+            name: "Coast".to_string(),
+            offset: HTOffset::from_sks(-20).unwrap(),
+            seasonal: None,
+        };
+        (ashkev, coast)
+    }
+
+    #[test]
+    fn applies_seasonal_shifts() {
+        let (ashkev, _) = zones();
+        let winter = HTDate::new(2019, MonthStatus::Greater, Month::Apress, 5, 0);
+        let summer = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 5, 0);
+        assert_eq!(ashkev.offset_at(&winter), HTOffset::from_sks(5).ok());
+        assert_eq!(ashkev.offset_at(&summer), HTOffset::from_sks(15).ok());
+        let wrapping = HTSeasonalShift {
+            first: (MonthStatus::Lesser, Month::Funny),
+            last: (MonthStatus::Greater, Month::Zero),
+            shift: HTOffset::UNIVERSAL,
+        };
+        assert!(in_season(&wrapping, (MonthStatus::Greater, Month::Zero)));
+        assert!(!in_season(&wrapping, (MonthStatus::Greater, Month::Niktvirin)));
+    }
+
+    #[test]
+    fn converts_between_zones() {
+        let (ashkev, coast) = zones();
+        let universal = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 5, 50 * 6000);
+        let zoned = HTZoned::new(universal, &ashkev);
+        assert_eq!(zoned.local().unwrap().date(), HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 5, 65 * 6000));
+        let there = zoned.to_zone(&coast).local().unwrap();
+        assert_eq!(there.date(), HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 5, 30 * 6000));
+        assert_eq!(there, zoned.local().unwrap());
+        assert_eq!(HTZoned::from_local(&zoned.local().unwrap().date(), &ashkev), Some(zoned));
+    }
+
+    fn local_day_and_epoch<T: HTTemporal>(value: &T) -> (u8, u128, u128) {
+        (value.day(), value.second(), value.to_epoch())
+    }
+
+    #[test]
+    fn works_as_a_temporal() {
+        let (ashkev, coast) = zones();
+        // 95 sks universal is the next day in Ashkev and the same day on the coast
+        let universal = HTDate::new(2019, MonthStatus::Lesser, Month::Zero, 5, 95 * 6000);
+        let epoch = universal.to_epoch();
+        assert_eq!(local_day_and_epoch(&HTZoned::new(universal, &ashkev)), (6, 10 * 6000, epoch));
+        assert_eq!(local_day_and_epoch(&HTZoned::new(universal, &coast)), (5, 75 * 6000, epoch));
+        assert_eq!(HTZoned::new(HTDate::EPOCH, &coast).to_htdate(), HTDate::EPOCH);
+    }
+
+    #[test]
+    fn keeps_zones_by_name() {
+        let (ashkev, coast) = zones();
+        let mut db = HTZoneDb::new();
+        db.insert(ashkev.clone());
+        db.insert(coast);
+        db.insert(ashkev.clone());
+        assert_eq!(db.names().collect::<Vec<_>>(), ["Ashkev", "Coast"]);
+        assert_eq!(db.get("Ashkev"), Some(&ashkev));
+        assert_eq!(db.get("Nowhere"), None);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn loads_zones_from_json() {
+        let (ashkev, coast) = zones();
+        let text = r#"{
+            "Ashkev": {"offset": "+05S0000R", "seasonal": {"first": "GS", "last": "LN", "shift": "+10S0000R"}},
+            "Coast": {"offset": "-20S0000R"}
+        }"#;
+        let db = HTZoneDb::from_json(text).unwrap();
+        assert_eq!(db.get("Ashkev"), Some(&ashkev));
+        assert_eq!(db.get("Coast"), Some(&coast));
+        assert_eq!(HTZoneDb::from_json(r#"{"Coast": {"offset": "20S"}}"#), Err(crate::HTParseError::MalformedString));
+    }
+}